@@ -0,0 +1,146 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// With the `embed-inputs` feature, writes a table of every non-empty input file in the
+/// `src/y<year>/inputs` folders so the binary can fall back to it when no input file is found.
+/// Also writes what each solution module implements and tests, for the `status` command, and
+/// sets the User-Agent sent to adventofcode.com.
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    set_user_agent();
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    let source_dir = Path::new(&env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("src");
+    write_sources(&source_dir, &out_dir);
+    let mut table = String::from("pub static EMBEDDED: &[(u16, u8, &str)] = &[\n");
+
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some() {
+        let mut inputs = Vec::new();
+        for entry in fs::read_dir(&source_dir).unwrap() {
+            let year_dir = entry.unwrap().path();
+            let Some(year) = year_of(&year_dir) else { continue };
+            let input_dir = year_dir.join("inputs");
+            if !input_dir.is_dir() {
+                continue;
+            }
+            println!("cargo:rerun-if-changed={}", input_dir.display());
+
+            for entry in fs::read_dir(&input_dir).unwrap() {
+                let path = entry.unwrap().path();
+                let Some(day) = day_of(&path) else { continue };
+                if fs::metadata(&path).map(|meta| meta.len() > 0).unwrap_or(false) {
+                    inputs.push((year, day, path));
+                }
+            }
+        }
+        inputs.sort();
+
+        for (year, day, path) in inputs {
+            table.push_str(&format!("    ({}, {}, include_str!({:?})),\n", year, day, path.display().to_string()));
+        }
+    }
+
+    table.push_str("];\n");
+    fs::write(out_dir.join("embedded_inputs.rs"), table).unwrap();
+}
+
+/// Sets `AOC_USER_AGENT` to the crate's name and version followed by its `repository` URL,
+/// which the site asks automated clients to include so their owner can be found.
+fn set_user_agent() {
+    let name = env::var("CARGO_PKG_NAME").unwrap();
+    let version = env::var("CARGO_PKG_VERSION").unwrap();
+    let repository = env::var("CARGO_PKG_REPOSITORY").unwrap_or_default();
+    let agent = if repository.is_empty() {
+        println!("cargo:warning=set `repository` in Cargo.toml; it goes in the User-Agent sent to adventofcode.com");
+        format!("{}/{}", name, version)
+    } else {
+        format!("{}/{} (+{})", name, version, repository)
+    };
+    println!("cargo:rustc-env=AOC_USER_AGENT={}", agent);
+}
+
+/// Writes `(year, key, part 1, part 2, example tests, ignored example tests)` for every
+/// `src/y<year>/day*.rs`.
+///
+/// A part counts as implemented when the module defines `fn partN(&self)`, i.e. overrides
+/// the `Solution` default that returns `Error::Unimplemented`.
+fn write_sources(source_dir: &Path, out_dir: &Path) {
+    let mut sources = Vec::new();
+    for entry in fs::read_dir(source_dir).unwrap() {
+        let year_dir = entry.unwrap().path();
+        let Some(year) = year_of(&year_dir) else { continue };
+        println!("cargo:rerun-if-changed={}", year_dir.display());
+
+        for entry in fs::read_dir(&year_dir).unwrap() {
+            let path = entry.unwrap().path();
+            let Some(key) = key_of(&path) else { continue };
+            println!("cargo:rerun-if-changed={}", path.display());
+            let source = fs::read_to_string(&path).unwrap();
+            let (examples, ignored) = example_tests(&source);
+            sources.push((
+                year,
+                key,
+                source.contains("fn part1(&self)"),
+                source.contains("fn part2(&self)"),
+                examples,
+                ignored,
+            ));
+        }
+    }
+    sources.sort();
+
+    let mut table = String::from("pub static SOURCES: &[(u16, &str, bool, bool, usize, usize)] = &[\n");
+    for (year, key, part1, part2, examples, ignored) in sources {
+        table.push_str(&format!("    ({}, {:?}, {}, {}, {}, {}),\n", year, key, part1, part2, examples, ignored));
+    }
+    table.push_str("];\n");
+    fs::write(out_dir.join("sources.rs"), table).unwrap();
+}
+
+/// How many `#[test]` functions named like `example` or `small_example` a module has, and how
+/// many of those are `#[ignore]`d. Other tests, e.g. of parse errors, do not count.
+fn example_tests(source: &str) -> (usize, usize) {
+    let (mut examples, mut ignored) = (0, 0);
+    let mut lines = source.lines().map(str::trim);
+    while let Some(line) = lines.next() {
+        if line != "#[test]" {
+            continue;
+        }
+        let mut is_ignored = false;
+        for line in lines.by_ref() {
+            if line.starts_with("#[ignore") {
+                is_ignored = true;
+            } else if !line.starts_with("#[") {
+                let name = line.strip_prefix("fn ").and_then(|rest| rest.split('(').next()).unwrap_or("");
+                if name.contains("example") {
+                    examples += 1;
+                    ignored += usize::from(is_ignored);
+                }
+                break;
+            }
+        }
+    }
+    (examples, ignored)
+}
+
+/// Registry key of a solution module named like `day06.rs` or `day14p2.rs`, i.e. `6` or `14p2`.
+fn key_of(path: &Path) -> Option<String> {
+    let stem = path.file_name()?.to_str()?.strip_suffix(".rs")?.strip_prefix("day")?;
+    let digits = stem.find(|c: char| !c.is_ascii_digit()).unwrap_or(stem.len());
+    let day: u8 = stem[..digits].parse().ok()?;
+    Some(format!("{}{}", day, &stem[digits..]))
+}
+
+/// Year of a source folder named like `y2024`.
+fn year_of(path: &Path) -> Option<u16> {
+    path.file_name()?.to_str()?.strip_prefix('y')?.parse().ok()
+}
+
+/// Day number of a file named like `day1.txt`, `day01.txt`, `day1` or `day01`.
+fn day_of(path: &Path) -> Option<u8> {
+    let name = path.file_name()?.to_str()?;
+    let stem = name.strip_suffix(".txt").unwrap_or(name);
+    stem.strip_prefix("day")?.parse().ok()
+}
@@ -0,0 +1,58 @@
+use std::fmt;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i64),
+    /// Unsigned values that do not fit in an `i64`.
+    BigInt(u128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{}", value),
+            Answer::BigInt(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+macro_rules! from_signed {
+    ($($ty:ty),*) => {$(
+        impl From<$ty> for Answer {
+            fn from(value: $ty) -> Self {
+                Answer::Int(value as i64)
+            }
+        }
+    )*};
+}
+
+macro_rules! from_unsigned {
+    ($($ty:ty),*) => {$(
+        impl From<$ty> for Answer {
+            fn from(value: $ty) -> Self {
+                match i64::try_from(value) {
+                    Ok(value) => Answer::Int(value),
+                    Err(_) => Answer::BigInt(value as u128),
+                }
+            }
+        }
+    )*};
+}
+
+from_signed!(i32, i64);
+from_unsigned!(u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::answer::Answer;
+
+/// Default location of the recorded answers, at the crate root.
+pub const ANSWERS_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml");
+
+// Keyed by (year, day, variant) so days sort numerically and `14p2` follows `14`.
+type Answers = BTreeMap<(u16, u8, String), BTreeMap<u8, Answer>>;
+
+/// How a freshly computed answer compares to the recorded one.
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer },
+    New,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {})", expected),
+            Verdict::New => write!(f, "NEW"),
+        }
+    }
+}
+
+/// Known-good answers, stored as a small TOML file with one table per year and day:
+///
+/// ```toml
+/// [2024.6]
+/// part1 = 4602
+/// part2 = 1703
+/// ```
+pub struct AnswerBook {
+    path: PathBuf,
+    answers: Answers,
+}
+
+impl AnswerBook {
+    /// Loads the answers at `path`; a missing file is an empty book.
+    pub fn load(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+
+        let answers = parse(&contents).map_err(|message| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), message))
+        })?;
+        Ok(AnswerBook { path, answers })
+    }
+
+    pub fn get(&self, year: u16, key: &str, part: u8) -> Option<&Answer> {
+        self.answers.get(&split_key(year, key)).and_then(|parts| parts.get(&part))
+    }
+
+    /// Compares `answer` with the recorded one; a `New` answer is only kept by [`AnswerBook::record`].
+    pub fn check(&self, year: u16, key: &str, part: u8, answer: &Answer) -> Verdict {
+        match self.get(year, key, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected: expected.clone() },
+            None => Verdict::New,
+        }
+    }
+
+    /// Records `answer` as the right one, replacing any answer recorded before.
+    pub fn record(&mut self, year: u16, key: &str, part: u8, answer: &Answer) {
+        self.answers.entry(split_key(year, key)).or_default().insert(part, answer.clone());
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        fs::write(&self.path, self.to_toml())
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn to_toml(&self) -> String {
+        let mut out = String::from("# Verified puzzle answers, keyed by year, day and part.\n");
+        for ((year, day, variant), parts) in &self.answers {
+            out.push_str(&format!("\n[{}.{}{}]\n", year, day, variant));
+            for (part, answer) in parts {
+                out.push_str(&format!("part{} = {}\n", part, format_value(answer)));
+            }
+        }
+        out
+    }
+}
+
+/// Splits a registry key such as `14p2` into its day number and variant.
+fn split_key(year: u16, key: &str) -> (u16, u8, String) {
+    let digits = key.find(|c: char| !c.is_ascii_digit()).unwrap_or(key.len());
+    let day = key[..digits].parse().unwrap_or(0);
+    (year, day, key[digits..].to_string())
+}
+
+fn parse(contents: &str) -> Result<Answers, String> {
+    let mut answers = Answers::new();
+    let mut table = None;
+
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let error = |message: &str| format!("line {}: {}", index + 1, message);
+
+        if let Some(name) = line.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+            let (year, key) = name
+                .trim()
+                .split_once('.')
+                .and_then(|(year, key)| Some((year.parse().ok()?, key.trim_matches('"'))))
+                .ok_or_else(|| error("expected a table like [2024.6]"))?;
+            table = Some(split_key(year, key));
+            continue;
+        }
+
+        let (key, value) = line.split_once('=').ok_or_else(|| error("expected `partN = value`"))?;
+        let part = key
+            .trim()
+            .strip_prefix("part")
+            .and_then(|part| part.parse::<u8>().ok())
+            .ok_or_else(|| error("expected a key like `part1`"))?;
+        let table = table.clone().ok_or_else(|| error("answer outside of a [year.day] table"))?;
+        let answer = parse_value(value.trim()).ok_or_else(|| error("invalid value"))?;
+        answers.entry(table).or_default().insert(part, answer);
+    }
+
+    Ok(answers)
+}
+
+/// Writes `answer` as a TOML value: numbers bare, text as a basic string that [`parse_value`]
+/// reads back unchanged.
+pub fn format_value(answer: &Answer) -> String {
+    let Answer::Text(text) = answer else { return answer.to_string() };
+    let mut value = String::from('"');
+    for c in text.chars() {
+        match c {
+            '"' => value.push_str("\\\""),
+            '\\' => value.push_str("\\\\"),
+            '\n' => value.push_str("\\n"),
+            '\t' => value.push_str("\\t"),
+            '\r' => value.push_str("\\r"),
+            c if c.is_control() => value.push_str(&format!("\\u{:04X}", c as u32)),
+            c => value.push(c),
+        }
+    }
+    value.push('"');
+    value
+}
+
+fn parse_value(value: &str) -> Option<Answer> {
+    if let Some(text) = value.strip_prefix('"').and_then(|rest| rest.strip_suffix('"')) {
+        return unescape(text).map(Answer::Text);
+    }
+    if let Ok(value) = value.parse::<i64>() {
+        return Some(Answer::Int(value));
+    }
+    value.parse::<u128>().ok().map(Answer::BigInt)
+}
+
+/// Undoes the escapes of a TOML basic string; `None` for an unknown or malformed escape.
+fn unescape(text: &str) -> Option<String> {
+    let mut unescaped = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        let escaped = match chars.next()? {
+            '"' => '"',
+            '\\' => '\\',
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            kind @ ('u' | 'U') => {
+                let length = if kind == 'u' { 4 } else { 8 };
+                let digits: String = chars.by_ref().take(length).collect();
+                if digits.len() != length || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return None;
+                }
+                char::from_u32(u32::from_str_radix(&digits, 16).ok()?)?
+            }
+            _ => return None,
+        };
+        unescaped.push(escaped);
+    }
+    Some(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_records_answers_on_request() {
+        let mut book = AnswerBook { path: PathBuf::from("answers.toml"), answers: Answers::new() };
+        assert_eq!(book.check(2024, "1", 1, &Answer::Int(10)), Verdict::New);
+        assert_eq!(book.check(2024, "1", 1, &Answer::Int(10)), Verdict::New);
+
+        book.record(2024, "1", 1, &Answer::Int(10));
+        assert_eq!(book.check(2024, "1", 1, &Answer::Int(11)), Verdict::Fail { expected: Answer::Int(10) });
+        book.record(2024, "1", 1, &Answer::Int(11));
+        assert_eq!(book.check(2024, "1", 1, &Answer::Int(11)), Verdict::Pass);
+    }
+
+    #[test]
+    fn reads_what_it_writes() {
+        let mut answers = Answers::new();
+        answers.entry((2024, 6, String::new())).or_default().insert(1, Answer::Int(4602));
+        answers.entry((2024, 6, String::new())).or_default().insert(2, Answer::BigInt(u128::from(u64::MAX) + 1));
+        let parts = answers.entry((2024, 14, "p2".to_string())).or_default();
+        parts.insert(1, Answer::Text("say \"hi\"\\".to_string()));
+        parts.insert(2, Answer::Text("#..#\n####\t\u{1b}".to_string()));
+        let book = AnswerBook { path: PathBuf::from("answers.toml"), answers };
+
+        let toml = book.to_toml();
+        assert!(toml.contains("\n[2024.6]\npart1 = 4602\npart2 = 18446744073709551616\n"), "{}", toml);
+        assert!(toml.contains("\n[2024.14p2]\npart1 = \"say \\\"hi\\\"\\\\\"\npart2 = \"#..#\\n####\\t\\u001B\"\n"), "{}", toml);
+        assert_eq!(parse(&toml).unwrap(), book.answers);
+    }
+
+    #[test]
+    fn reports_malformed_lines() {
+        assert_eq!(parse("[2024.1]\npart1 = \"a\\qb\"\n").unwrap_err(), "line 2: invalid value");
+        assert_eq!(parse("part1 = 3\n").unwrap_err(), "line 1: answer outside of a [year.day] table");
+        assert_eq!(parse("[2024.1]\nanswer = 3\n").unwrap_err(), "line 2: expected a key like `part1`");
+        assert_eq!(parse("[day one]\n").unwrap_err(), "line 1: expected a table like [2024.6]");
+    }
+}
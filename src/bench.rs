@@ -0,0 +1,134 @@
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+use crate::input;
+use crate::solution::Entry;
+
+/// Summary statistics over the samples of one phase.
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Returns `None` when there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Some(Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Runs `entry` `runs` times, timing parsing and each part separately, and prints the statistics.
+pub fn bench(entry: &Entry, runs: usize, input: Option<&Path>) -> Result<()> {
+    let meta = &entry.meta;
+    let input = input::load(meta.year, meta.day, input)?;
+
+    let mut parse_times = Vec::with_capacity(runs);
+    let mut part_times = [Vec::with_capacity(runs), Vec::with_capacity(runs)];
+    let mut implemented = [true, true];
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let puzzle = (entry.parse)(&input.text).map_err(|err| err.in_file(input.origin.label()))?;
+        parse_times.push(start.elapsed());
+
+        for (index, times) in part_times.iter_mut().enumerate() {
+            if !implemented[index] {
+                continue;
+            }
+            let start = Instant::now();
+            let answer = if index == 0 { puzzle.part1() } else { puzzle.part2() };
+            let elapsed = start.elapsed();
+            match answer {
+                Ok(_) => times.push(elapsed),
+                Err(Error::Unimplemented) => implemented[index] = false,
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    println!("{} day {} - {}, {} runs", meta.year, meta.key(), meta.title, runs);
+    println!("{:<8} {:>12} {:>12} {:>12} {:>12}", "phase", "min", "median", "mean", "stddev");
+    print_stats("parse", &parse_times);
+    for (index, times) in part_times.iter().enumerate() {
+        let phase = format!("part {}", index + 1);
+        if implemented[index] {
+            print_stats(&phase, times);
+        } else {
+            println!("{:<8} {:>12}", phase, "unimplemented");
+        }
+    }
+
+    Ok(())
+}
+
+fn print_stats(phase: &str, samples: &[Duration]) {
+    if let Some(stats) = Stats::from_samples(samples) {
+        println!(
+            "{:<8} {:>12} {:>12} {:>12} {:>12}",
+            phase,
+            format!("{:.3?}", stats.min),
+            format!("{:.3?}", stats.median),
+            format!("{:.3?}", stats.mean),
+            format!("{:.3?}", stats.stddev),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(seconds: &[u64]) -> Stats {
+        let samples: Vec<Duration> = seconds.iter().map(|&secs| Duration::from_secs(secs)).collect();
+        Stats::from_samples(&samples).unwrap()
+    }
+
+    #[test]
+    fn takes_the_middle_sample_of_an_odd_count() {
+        let stats = stats(&[3, 1, 5]);
+        assert_eq!(stats.min, Duration::from_secs(1));
+        assert_eq!(stats.median, Duration::from_secs(3));
+        assert_eq!(stats.mean, Duration::from_secs(3));
+        assert!((stats.stddev.as_secs_f64() - (8.0f64 / 3.0).sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn averages_the_middle_samples_of_an_even_count() {
+        let stats = stats(&[4, 1, 6, 2]);
+        assert_eq!(stats.min, Duration::from_secs(1));
+        assert_eq!(stats.median, Duration::from_secs(3));
+        assert_eq!(stats.mean, Duration::from_millis(3250));
+        assert!((stats.stddev.as_secs_f64() - 3.6875f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn needs_a_sample() {
+        assert!(Stats::from_samples(&[]).is_none());
+        assert_eq!(stats(&[7]).stddev, Duration::ZERO);
+    }
+}
@@ -0,0 +1,95 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::error::{Error, Result};
+
+/// Shared flag telling a running solution to give up.
+#[derive(Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+thread_local! {
+    /// The token of the run on this thread, installed by [`with_budget`].
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+/// Whether the run on this thread has used up its time budget.
+///
+/// Cheap enough to call on every iteration of a hot loop or recursion.
+pub fn is_cancelled() -> bool {
+    CURRENT.with(|current| current.borrow().as_ref().is_some_and(CancellationToken::is_cancelled))
+}
+
+/// `Err(Error::Timeout)` once the run on this thread has used up its time budget.
+///
+/// Long-running solutions call this in their outer loops and bail out with `?`.
+pub fn check() -> Result<()> {
+    if is_cancelled() { Err(Error::Timeout) } else { Ok(()) }
+}
+
+/// Runs `f` with a token that a watchdog thread cancels once `budget` has passed.
+///
+/// Cancellation is cooperative: `f` only stops early if it calls [`check`] or [`is_cancelled`].
+pub fn with_budget<T>(budget: Option<Duration>, f: impl FnOnce() -> T) -> T {
+    let Some(budget) = budget else { return f() };
+
+    let token = CancellationToken::default();
+    let (done, finished) = mpsc::channel::<()>();
+    let watchdog = {
+        let token = token.clone();
+        thread::spawn(move || {
+            if let Err(RecvTimeoutError::Timeout) = finished.recv_timeout(budget) {
+                token.cancel();
+            }
+        })
+    };
+
+    // Restores the previous token even if `f` panics
+    struct Installed(Option<CancellationToken>);
+    impl Drop for Installed {
+        fn drop(&mut self) {
+            CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+        }
+    }
+    let installed = Installed(CURRENT.with(|current| current.borrow_mut().replace(token)));
+
+    let result = f();
+    drop(installed);
+    drop(done);
+    let _ = watchdog.join();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancels_once_the_budget_is_spent() {
+        let result: Result<()> = with_budget(Some(Duration::from_millis(20)), || loop {
+            check()?;
+            thread::sleep(Duration::from_millis(1));
+        });
+        assert!(matches!(result, Err(Error::Timeout)));
+        assert!(!is_cancelled());
+    }
+
+    #[test]
+    fn runs_to_completion_within_the_budget() {
+        assert_eq!(with_budget(Some(Duration::from_secs(10)), || check().map(|_| 42)).ok(), Some(42));
+        assert!(check().is_ok());
+    }
+}
@@ -0,0 +1,518 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const USAGE: &str = "\
+Usage: advent_of_code [COMMAND]
+
+Commands:
+  run --day <DAY> [--part <1|2>] [--input <PATH>]   Run one day (DAY may be a variant like 14p2)
+  all                                              Run every registered day
+  bench --day <DAY> [--runs <N>] [--input <PATH>]  Time parsing and each part over N runs (default 10)
+  list                                             List registered days
+  status                                           Show which parts are implemented, example tests,
+                                                   whether inputs are present, and verified answers
+  new <DAY> [--title <TITLE>]                      Create the module, test stub, input file and
+                                                   registry entry for a new day
+  watch --day <DAY> [--part <1|2>] [--input <PATH>]
+                                                   Re-run the day's tests and solution whenever
+                                                   the sources or its input change
+  fetch <DAY>                                      Download the day's input into the input directory,
+                                                   unless it is already there
+  submit --day <DAY> --part <1|2>                  Solve the part and send its answer, unless an
+                                                   earlier reply rules it out or asked to wait
+  examples <DAY> [--page <PATH>]                   Save the example input and answers from the
+                                                   puzzle description, a saved page or the site's,
+                                                   and fill in the day's example test
+  help                                             Show this message
+
+Options for every command:
+  -v, --verbose          Log debug output from the solutions; repeat (-vv) for trace output.
+                         $AOC_LOG sets the starting level: error, warn, info, debug or trace
+
+Options for run, all, bench, list, status, new, watch, fetch, submit and examples:
+  --year <YEAR>          Advent of Code event; all, list and status cover every year when it
+                         is not given, the others default to the latest registered year
+
+Options for run and bench:
+  --param <NAME=VALUE>   Override a puzzle constant, e.g. --param width=11 --param height=7 for
+                         an example input; repeatable, and `list` shows each day's parameters.
+                         Answers are not verified while a parameter is overridden
+
+Options for run and all:
+  --format <text|json>   Output format; json prints one object per day and part, one per line
+  --timeout <DURATION>   Time budget for parsing and for each part, e.g. 30s, 500ms or 2m;
+                         solutions that check for cancellation stop and report TIMEOUT
+  --record               Save NEW answers to answers.toml as the verified ones; without it
+                         answers are only checked. submit saves the answers the site accepts
+
+Inputs are read from --input (`-` for stdin), else from $AOC_INPUT_DIR/<YEAR> or the configured
+input_dir/<YEAR>, else from the crate's src/y<YEAR>/inputs; files may be named day1.txt,
+day01.txt, day1 or day01, optionally gzipped (day1.txt.gz) or bundled in an inputs.zip in the
+same directory. fetch saves inputs to the first of those directories, and examples saves
+dayN.example.txt and dayN.example.toml to src/y<YEAR>/inputs. fetch, submit and examples without
+--page need the session cookie of a logged-in adventofcode.com user in $AOC_SESSION or aoc.toml,
+and submit keeps every reply in submissions.log at the crate root. https:// requests go through
+the external `curl` program, which must be on the PATH.
+
+Settings are read from aoc.toml in the working directory or else the crate root, and flags win
+over them; artifact_dir is where solutions save pictures and other extra output:
+  input_dir = \"../inputs\"   year = 2024   artifact_dir = \"target/out\"   format = \"json\"
+
+With no command, an interactive prompt reads commands such as `run 6 2` from stdin and keeps
+parsed inputs between them; type `help` there for the full list.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        year: Option<u16>,
+        day: String,
+        part: Option<u8>,
+        input: Option<PathBuf>,
+        format: Option<Format>,
+        timeout: Option<Duration>,
+        params: Vec<(String, u64)>,
+        record: bool,
+    },
+    All {
+        year: Option<u16>,
+        format: Option<Format>,
+        timeout: Option<Duration>,
+        record: bool,
+    },
+    Bench {
+        year: Option<u16>,
+        day: String,
+        runs: usize,
+        input: Option<PathBuf>,
+        params: Vec<(String, u64)>,
+    },
+    List {
+        year: Option<u16>,
+    },
+    Status {
+        year: Option<u16>,
+    },
+    New {
+        year: Option<u16>,
+        day: u8,
+        title: Option<String>,
+    },
+    Watch {
+        year: Option<u16>,
+        day: String,
+        part: Option<u8>,
+        input: Option<PathBuf>,
+    },
+    Fetch {
+        year: Option<u16>,
+        day: u8,
+    },
+    Submit {
+        year: Option<u16>,
+        day: String,
+        part: u8,
+    },
+    Examples {
+        year: Option<u16>,
+        day: u8,
+        page: Option<PathBuf>,
+    },
+    Help,
+    /// No arguments given: start the interactive prompt.
+    Repl,
+}
+
+/// Parses the process arguments (without the program name) into a `Command`.
+pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    let command = match args.next() {
+        Some(command) => command,
+        None => return Ok(Command::Repl),
+    };
+
+    match command.as_str() {
+        "run" => parse_run(args),
+        "all" => parse_all(args),
+        "bench" => parse_bench(args),
+        "list" => Ok(Command::List { year: parse_year_only(args, "list")? }),
+        "status" => Ok(Command::Status { year: parse_year_only(args, "status")? }),
+        "new" => parse_new(args),
+        "watch" => parse_watch(args),
+        "fetch" => parse_fetch(args),
+        "submit" => parse_submit(args),
+        "examples" => parse_examples(args),
+        "help" | "-h" | "--help" => Ok(Command::Help),
+        other => Err(format!("unknown command '{}'", other)),
+    }
+}
+
+/// Options that take a value; the argument after one is its value even if it looks like `-v`.
+const VALUE_OPTIONS: &[&str] = &[
+    "--year", "-y", "--day", "-d", "--part", "-p", "--input", "-i", "--format", "-f", "--timeout", "-t",
+    "--param", "--runs", "-n", "--title", "--page",
+];
+
+/// Removes the `-v`, `-vv`, ... and `--verbose` flags from `args`, counting each `v`.
+///
+/// They may come before or after the command, but not in place of an option's value.
+pub fn take_verbosity(args: impl Iterator<Item = String>) -> (Vec<String>, u8) {
+    let mut verbosity = 0u8;
+    let mut rest = Vec::new();
+    let mut is_value = false;
+    for arg in args {
+        let count = match arg.strip_prefix('-') {
+            _ if is_value => 0,
+            Some("-verbose") => 1,
+            Some(flags) if !flags.is_empty() && flags.bytes().all(|flag| flag == b'v') => flags.len(),
+            _ => 0,
+        };
+        if count == 0 {
+            is_value = !is_value && VALUE_OPTIONS.contains(&arg.as_str());
+            rest.push(arg);
+        } else {
+            verbosity = verbosity.saturating_add(count as u8);
+        }
+    }
+    (rest, verbosity)
+}
+
+fn parse_run(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = None;
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut format = None;
+    let mut timeout = None;
+    let mut params = Vec::new();
+    let mut record = false;
+
+    while let Some(flag) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("missing value for '{}'", flag));
+        match flag.as_str() {
+            "--year" | "-y" => year = Some(parse_year(&value()?)?),
+            "--day" | "-d" => day = Some(value()?),
+            "--param" => params.push(parse_param(&value()?)?),
+            "--part" | "-p" => part = Some(parse_part(&value()?)?),
+            "--input" | "-i" => input = Some(PathBuf::from(value()?)),
+            "--format" | "-f" => format = Some(parse_format(&value()?)?),
+            "--timeout" | "-t" => timeout = Some(parse_duration(&value()?)?),
+            "--record" => record = true,
+            other => return Err(format!("unknown option '{}' for 'run'", other)),
+        }
+    }
+
+    let day = day.ok_or("'run' needs --day")?;
+    Ok(Command::Run { year, day, part, input, format, timeout, params, record })
+}
+
+fn parse_all(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = None;
+    let mut format = None;
+    let mut timeout = None;
+    let mut record = false;
+
+    while let Some(flag) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("missing value for '{}'", flag));
+        match flag.as_str() {
+            "--year" | "-y" => year = Some(parse_year(&value()?)?),
+            "--format" | "-f" => format = Some(parse_format(&value()?)?),
+            "--timeout" | "-t" => timeout = Some(parse_duration(&value()?)?),
+            "--record" => record = true,
+            other => return Err(format!("unknown option '{}' for 'all'", other)),
+        }
+    }
+
+    Ok(Command::All { year, format, timeout, record })
+}
+
+fn parse_bench(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = None;
+    let mut day = None;
+    let mut runs = 10;
+    let mut input = None;
+    let mut params = Vec::new();
+
+    while let Some(flag) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("missing value for '{}'", flag));
+        match flag.as_str() {
+            "--year" | "-y" => year = Some(parse_year(&value()?)?),
+            "--day" | "-d" => day = Some(value()?),
+            "--runs" | "-n" => runs = parse_runs(&value()?)?,
+            "--input" | "-i" => input = Some(PathBuf::from(value()?)),
+            "--param" => params.push(parse_param(&value()?)?),
+            other => return Err(format!("unknown option '{}' for 'bench'", other)),
+        }
+    }
+
+    let day = day.ok_or("'bench' needs --day")?;
+    Ok(Command::Bench { year, day, runs, input, params })
+}
+
+/// Parses the options of a command that only takes `--year`.
+fn parse_year_only(mut args: impl Iterator<Item = String>, command: &str) -> Result<Option<u16>, String> {
+    let mut year = None;
+
+    while let Some(flag) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("missing value for '{}'", flag));
+        match flag.as_str() {
+            "--year" | "-y" => year = Some(parse_year(&value()?)?),
+            other => return Err(format!("unknown option '{}' for '{}'", other, command)),
+        }
+    }
+
+    Ok(year)
+}
+
+fn parse_new(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = None;
+    let mut day = None;
+    let mut title = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("missing value for '{}'", arg));
+        match arg.as_str() {
+            "--year" | "-y" => year = Some(parse_year(&value()?)?),
+            "--title" | "-t" => title = Some(value()?),
+            other if day.is_none() && !other.starts_with('-') => day = Some(parse_day(other)?),
+            other => return Err(format!("unknown option '{}' for 'new'", other)),
+        }
+    }
+
+    let day = day.ok_or("'new' needs a day")?;
+    Ok(Command::New { year, day, title })
+}
+
+fn parse_watch(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = None;
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+
+    while let Some(flag) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("missing value for '{}'", flag));
+        match flag.as_str() {
+            "--year" | "-y" => year = Some(parse_year(&value()?)?),
+            "--day" | "-d" => day = Some(value()?),
+            "--part" | "-p" => part = Some(parse_part(&value()?)?),
+            "--input" | "-i" => input = Some(PathBuf::from(value()?)),
+            other => return Err(format!("unknown option '{}' for 'watch'", other)),
+        }
+    }
+
+    let day = day.ok_or("'watch' needs --day")?;
+    Ok(Command::Watch { year, day, part, input })
+}
+
+/// Parses a parameter override like `width=11`.
+fn parse_param(value: &str) -> Result<(String, u64), String> {
+    let (name, number) = value
+        .split_once('=')
+        .ok_or_else(|| format!("parameter must look like NAME=VALUE, got '{}'", value))?;
+    let number = number
+        .trim()
+        .parse()
+        .map_err(|_| format!("parameter '{}' must be a non-negative integer, got '{}'", name, number))?;
+    Ok((name.trim().to_string(), number))
+}
+
+fn parse_fetch(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = None;
+    let mut day = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("missing value for '{}'", arg));
+        match arg.as_str() {
+            "--year" | "-y" => year = Some(parse_year(&value()?)?),
+            "--day" | "-d" => day = Some(parse_day(&value()?)?),
+            other if day.is_none() && !other.starts_with('-') => day = Some(parse_day(other)?),
+            other => return Err(format!("unknown option '{}' for 'fetch'", other)),
+        }
+    }
+
+    let day = day.ok_or("'fetch' needs a day")?;
+    Ok(Command::Fetch { year, day })
+}
+
+fn parse_submit(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = None;
+    let mut day = None;
+    let mut part = None;
+
+    while let Some(flag) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("missing value for '{}'", flag));
+        match flag.as_str() {
+            "--year" | "-y" => year = Some(parse_year(&value()?)?),
+            "--day" | "-d" => day = Some(value()?),
+            "--part" | "-p" => part = Some(parse_part(&value()?)?),
+            other => return Err(format!("unknown option '{}' for 'submit'", other)),
+        }
+    }
+
+    let day = day.ok_or("'submit' needs --day")?;
+    let part = part.ok_or("'submit' needs --part")?;
+    Ok(Command::Submit { year, day, part })
+}
+
+fn parse_examples(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = None;
+    let mut day = None;
+    let mut page = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("missing value for '{}'", arg));
+        match arg.as_str() {
+            "--year" | "-y" => year = Some(parse_year(&value()?)?),
+            "--day" | "-d" => day = Some(parse_day(&value()?)?),
+            "--page" => page = Some(PathBuf::from(value()?)),
+            other if day.is_none() && !other.starts_with('-') => day = Some(parse_day(other)?),
+            other => return Err(format!("unknown option '{}' for 'examples'", other)),
+        }
+    }
+
+    let day = day.ok_or("'examples' needs a day")?;
+    Ok(Command::Examples { year, day, page })
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.parse() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("day must be between 1 and 25, got '{}'", value)),
+    }
+}
+
+fn parse_year(value: &str) -> Result<u16, String> {
+    match value.parse() {
+        Ok(year) if year >= 2015 => Ok(year),
+        _ => Err(format!("year must be 2015 or later, got '{}'", value)),
+    }
+}
+
+fn parse_runs(value: &str) -> Result<usize, String> {
+    match value.parse() {
+        Ok(runs) if runs > 0 => Ok(runs),
+        _ => Err(format!("runs must be a positive number, got '{}'", value)),
+    }
+}
+
+/// Parses a duration such as `30`, `30s`, `500ms` or `2m`; a bare number is in seconds.
+fn parse_duration(value: &str) -> Result<Duration, String> {
+    let digits = value.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(value.len());
+    let amount: f64 = value[..digits].parse().map_err(|_| format!("invalid duration '{}'", value))?;
+    let seconds = match &value[digits..] {
+        "" | "s" => amount,
+        "ms" => amount / 1000.0,
+        "m" => amount * 60.0,
+        _ => return Err(format!("invalid duration '{}', expected e.g. 30s, 500ms or 2m", value)),
+    };
+    Duration::try_from_secs_f64(seconds)
+        .ok()
+        .filter(|duration| !duration.is_zero())
+        .ok_or_else(|| format!("invalid duration '{}'", value))
+}
+
+pub fn parse_format(value: &str) -> Result<Format, String> {
+    match value {
+        "text" => Ok(Format::Text),
+        "json" => Ok(Format::Json),
+        other => Err(format!("format must be text or json, got '{}'", other)),
+    }
+}
+
+pub(crate) fn parse_part(value: &str) -> Result<u8, String> {
+    match value {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        other => Err(format!("part must be 1 or 2, got '{}'", other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    fn parse_line(line: &str) -> Result<Command, String> {
+        parse(args(line))
+    }
+
+    #[test]
+    fn counts_verbosity_flags_but_not_option_values() {
+        assert_eq!(take_verbosity(args("-v run --day 1 -vv").into_iter()), (args("run --day 1"), 3));
+        assert_eq!(take_verbosity(args("--verbose list").into_iter()), (args("list"), 1));
+        assert_eq!(take_verbosity(args("new 5 --title -v").into_iter()), (args("new 5 --title -v"), 0));
+        assert_eq!(take_verbosity(args("run -i -vv -v").into_iter()), (args("run -i -vv"), 1));
+    }
+
+    #[test]
+    fn parses_each_command() {
+        assert_eq!(parse_line(""), Ok(Command::Repl));
+        assert_eq!(parse_line("--help"), Ok(Command::Help));
+        assert_eq!(
+            parse_line("run -d 14p2 -p 2 -i - -f json -t 500ms --param width=11 --record --year 2024"),
+            Ok(Command::Run {
+                year: Some(2024),
+                day: "14p2".to_string(),
+                part: Some(2),
+                input: Some(PathBuf::from("-")),
+                format: Some(Format::Json),
+                timeout: Some(Duration::from_millis(500)),
+                params: vec![("width".to_string(), 11)],
+                record: true,
+            })
+        );
+        assert_eq!(
+            parse_line("all --timeout 2m"),
+            Ok(Command::All { year: None, format: None, timeout: Some(Duration::from_secs(120)), record: false })
+        );
+        assert_eq!(
+            parse_line("bench --day 6 -n 3"),
+            Ok(Command::Bench { year: None, day: "6".to_string(), runs: 3, input: None, params: vec![] })
+        );
+        assert_eq!(parse_line("list -y 2024"), Ok(Command::List { year: Some(2024) }));
+        assert_eq!(parse_line("status"), Ok(Command::Status { year: None }));
+        assert_eq!(
+            parse_line("new 7 --title Bridge"),
+            Ok(Command::New { year: None, day: 7, title: Some("Bridge".to_string()) })
+        );
+        assert_eq!(
+            parse_line("watch --day 3 --part 1"),
+            Ok(Command::Watch { year: None, day: "3".to_string(), part: Some(1), input: None })
+        );
+        assert_eq!(parse_line("fetch 9"), Ok(Command::Fetch { year: None, day: 9 }));
+        assert_eq!(parse_line("fetch --day 9"), Ok(Command::Fetch { year: None, day: 9 }));
+        assert_eq!(
+            parse_line("submit --day 2 --part 1"),
+            Ok(Command::Submit { year: None, day: "2".to_string(), part: 1 })
+        );
+        assert_eq!(
+            parse_line("examples 1 --page day1.html"),
+            Ok(Command::Examples { year: None, day: 1, page: Some(PathBuf::from("day1.html")) })
+        );
+    }
+
+    #[test]
+    fn reports_bad_arguments() {
+        assert_eq!(parse_line("frobnicate"), Err("unknown command 'frobnicate'".to_string()));
+        assert_eq!(parse_line("run --day 1 --fast"), Err("unknown option '--fast' for 'run'".to_string()));
+        assert_eq!(parse_line("status --day 1"), Err("unknown option '--day' for 'status'".to_string()));
+        assert_eq!(parse_line("run --day"), Err("missing value for '--day'".to_string()));
+        assert_eq!(parse_line("run --part 1"), Err("'run' needs --day".to_string()));
+        assert_eq!(parse_line("submit --day 1"), Err("'submit' needs --part".to_string()));
+        assert_eq!(parse_line("fetch 26"), Err("day must be between 1 and 25, got '26'".to_string()));
+        assert_eq!(parse_line("new zero"), Err("day must be between 1 and 25, got 'zero'".to_string()));
+        assert_eq!(parse_line("run --day 1 --part 3"), Err("part must be 1 or 2, got '3'".to_string()));
+        assert_eq!(parse_line("all --year 2014"), Err("year must be 2015 or later, got '2014'".to_string()));
+        assert!(parse_line("run --day 1 --timeout 0s").is_err());
+        assert!(parse_line("run --day 1 --param width").is_err());
+        assert!(parse_line("bench --day 1 --runs 0").is_err());
+    }
+}
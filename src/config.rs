@@ -0,0 +1,159 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::cli::{self, Format};
+
+/// Name of the project configuration file, looked for in the working directory and then at
+/// the crate root.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Project settings from `aoc.toml`; command-line flags and environment variables win over them.
+///
+/// ```toml
+/// input_dir = "../aoc-inputs"   # holds one folder per year, like $AOC_INPUT_DIR
+/// year = 2024                   # default for --year
+/// artifact_dir = "target/out"   # where solutions write pictures and other extra output
+/// format = "json"               # default for --format
+/// session = "53616c746564..."   # adventofcode.com session cookie, like $AOC_SESSION
+/// base_url = "http://localhost:8080"  # a stand-in for adventofcode.com, like $AOC_BASE_URL
+/// ```
+///
+/// Relative paths are relative to the file's directory.
+#[derive(Debug, Default, PartialEq)]
+pub struct Config {
+    pub input_dir: Option<PathBuf>,
+    pub year: Option<u16>,
+    pub artifact_dir: Option<PathBuf>,
+    pub format: Option<Format>,
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Reads the configuration file, if there is one; call once at startup.
+pub fn init() -> Result<(), String> {
+    let config = match find() {
+        Some(path) => load(&path)?,
+        None => Config::default(),
+    };
+    let _ = CONFIG.set(config);
+    Ok(())
+}
+
+/// The configuration read by [`init`], or the defaults before that.
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+fn find() -> Option<PathBuf> {
+    let candidates = [env::current_dir().ok(), Some(PathBuf::from(env!("CARGO_MANIFEST_DIR")))];
+    candidates.into_iter().flatten().map(|dir| dir.join(CONFIG_FILE)).find(|path| path.is_file())
+}
+
+fn load(path: &Path) -> Result<Config, String> {
+    let contents = fs::read_to_string(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+    let base = path.parent().unwrap_or(Path::new("."));
+    parse(&contents, base).map_err(|message| format!("{}: {}", path.display(), message))
+}
+
+fn parse(contents: &str, base: &Path) -> Result<Config, String> {
+    let mut config = Config::default();
+
+    for (index, line) in contents.lines().enumerate() {
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let error = |message: String| format!("line {}: {}", index + 1, message);
+        let (key, value) = line.split_once('=').ok_or_else(|| error("expected `key = value`".to_string()))?;
+        let value = value.trim();
+        let string = || {
+            value
+                .strip_prefix('"')
+                .and_then(|rest| rest.strip_suffix('"'))
+                .ok_or_else(|| error(format!("expected a quoted string, got {}", value)))
+        };
+
+        match key.trim() {
+            "input_dir" => config.input_dir = Some(base.join(string()?)),
+            "artifact_dir" => config.artifact_dir = Some(base.join(string()?)),
+            "year" => {
+                let year = value.parse().map_err(|_| error(format!("expected a year, got {}", value)))?;
+                config.year = Some(year);
+            }
+            "format" => config.format = Some(cli::parse_format(string()?).map_err(error)?),
+            "session" => config.session = Some(string()?.to_string()),
+            "base_url" => config.base_url = Some(string()?.to_string()),
+            other => return Err(error(format!("unknown setting '{}'", other))),
+        }
+    }
+
+    Ok(config)
+}
+
+/// Drops a `#` comment, unless the `#` is inside a quoted string.
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    for (index, c) in line.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..index],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Where a solution should write the extra file `name`, creating the artifact directory if needed.
+///
+/// Without an `artifact_dir` setting, files go to the working directory.
+pub fn artifact_path(name: &str) -> io::Result<PathBuf> {
+    match &get().artifact_dir {
+        Some(dir) => {
+            fs::create_dir_all(dir)?;
+            Ok(dir.join(name))
+        }
+        None => Ok(PathBuf::from(name)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_setting() {
+        let contents = "\
+# project settings
+input_dir = \"inputs\"  # next to this file
+year = 2024
+artifact_dir = \"/tmp/aoc #1\"
+format = \"json\"
+session = \"abc123\"
+";
+        let config = parse(contents, Path::new("/work")).unwrap();
+        assert_eq!(
+            config,
+            Config {
+                input_dir: Some(PathBuf::from("/work/inputs")),
+                year: Some(2024),
+                artifact_dir: Some(PathBuf::from("/tmp/aoc #1")),
+                format: Some(Format::Json),
+                session: Some("abc123".to_string()),
+                base_url: None,
+            }
+        );
+    }
+
+    #[test]
+    fn reports_the_offending_line() {
+        let err = parse("year = 2024\ncolour = \"red\"\n", Path::new(".")).unwrap_err();
+        assert_eq!(err, "line 2: unknown setting 'colour'");
+        assert!(parse("format = \"xml\"\n", Path::new(".")).is_err());
+    }
+}
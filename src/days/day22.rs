@@ -1,3 +0,0 @@
-pub fn run() {
-    print!("Not yet!!")
-}
\ No newline at end of file
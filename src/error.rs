@@ -0,0 +1,188 @@
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+/// Everything that can go wrong while loading, parsing or solving a puzzle.
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// The input does not have the expected shape.
+    Parse(ParseError),
+    /// The input parsed but describes a puzzle that cannot be solved, e.g. a maze without a path.
+    Puzzle(String),
+    /// The part has no solution yet.
+    Unimplemented,
+    /// The run gave up because its time budget was spent; see `cancel::check`.
+    Timeout,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// What a parser expected and, when it can point at it, where in the input it was let down.
+#[derive(Debug)]
+pub struct ParseError {
+    /// What the parser wanted, phrased to follow "expected", e.g. "a number".
+    pub expected: String,
+    /// The offending text.
+    pub found: Option<String>,
+    /// Address of `found` in the text being parsed, turned into `location` by [`Error::locate`].
+    span: Option<usize>,
+    pub location: Option<Location>,
+    /// The input file, filled in by whoever loaded it.
+    pub file: Option<String>,
+}
+
+/// A 1-based position in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Error {
+    /// A parse error about the input as a whole, e.g. a missing section.
+    pub fn parse(expected: impl Into<String>) -> Self {
+        Error::Parse(ParseError { expected: expected.into(), found: None, span: None, location: None, file: None })
+    }
+
+    /// A parse error pointing at `found`, which should be a slice of the input so that
+    /// [`Error::locate`] can work out its line and column.
+    pub fn unexpected(found: &str, expected: impl Into<String>) -> Self {
+        Error::Parse(ParseError {
+            expected: expected.into(),
+            found: Some(found.to_string()),
+            span: Some(found.as_ptr() as usize),
+            location: None,
+            file: None,
+        })
+    }
+
+    pub fn puzzle(message: impl Into<String>) -> Self {
+        Error::Puzzle(message.into())
+    }
+
+    /// Fills in the line and column of a parse error raised while parsing `input`.
+    pub fn locate(mut self, input: &str) -> Self {
+        if let Error::Parse(err) = &mut self {
+            // Only a `found` lying wholly inside `input` is a slice of it
+            let start = input.as_ptr() as usize;
+            let length = err.found.as_ref().map_or(0, String::len);
+            let offset = err.span.and_then(|span| span.checked_sub(start));
+            if let Some(offset) = offset.filter(|&offset| offset + length <= input.len()) {
+                err.location = Some(Location::of(input, offset));
+            }
+        }
+        self
+    }
+
+    /// Names the file a parse error was found in.
+    pub fn in_file(mut self, file: impl Into<String>) -> Self {
+        if let Error::Parse(err) = &mut self {
+            err.file = Some(file.into());
+        }
+        self
+    }
+}
+
+impl Location {
+    /// The position of byte `offset` in `text`, counting columns in characters.
+    fn of(text: &str, offset: usize) -> Self {
+        let before = &text.as_bytes()[..offset];
+        let line_start = before.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+        Location {
+            line: before.iter().filter(|&&b| b == b'\n').count() + 1,
+            column: String::from_utf8_lossy(&before[line_start..]).chars().count() + 1,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.file, self.location) {
+            (Some(file), Some(at)) => write!(f, "{}:{}:{}: ", file, at.line, at.column)?,
+            (None, Some(at)) => write!(f, "line {}, column {}: ", at.line, at.column)?,
+            (Some(file), None) => write!(f, "{}: ", file)?,
+            (None, None) => {}
+        }
+        write!(f, "expected {}", self.expected)?;
+        if let Some(found) = &self.found {
+            // Only the first line, so a bad section does not flood the terminal
+            match found.lines().next() {
+                Some(line) if line.len() < found.trim_end().len() => write!(f, ", found '{}...'", line)?,
+                _ => write!(f, ", found '{}'", found.trim_end())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "{}", err),
+            Error::Parse(err) => write!(f, "parse error: {}", err),
+            Error::Puzzle(message) => write!(f, "{}", message),
+            Error::Unimplemented => write!(f, "not implemented yet"),
+            Error::Timeout => write!(f, "ran out of time"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+/// Parses a number, pointing at the offending text when it is not one.
+pub fn parse_num<T: FromStr>(text: &str) -> Result<T> {
+    let text = text.trim();
+    text.parse().map_err(|_| Error::unexpected(text, "a number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn location(err: Error) -> Option<Location> {
+        match err {
+            Error::Parse(err) => err.location,
+            other => panic!("not a parse error: {}", other),
+        }
+    }
+
+    #[test]
+    fn locates_found_text_on_later_lines() {
+        let input = "1 2\n3 x\n4 5\n";
+        let err = Error::unexpected(&input[6..7], "a number").locate(input);
+        assert_eq!(location(err), Some(Location { line: 2, column: 3 }));
+
+        let err = Error::unexpected(&input[4..], "a number").locate(input).in_file("day1.txt");
+        assert_eq!(err.to_string(), "parse error: day1.txt:2:1: expected a number, found '3 x...'");
+    }
+
+    #[test]
+    fn counts_columns_in_characters() {
+        let input = "ok\nαβ x";
+        let err = Error::unexpected(&input[input.len() - 1..], "a digit").locate(input);
+        assert_eq!(location(err), Some(Location { line: 2, column: 4 }));
+    }
+
+    #[test]
+    fn text_from_elsewhere_has_no_location() {
+        let input = "1 2\n";
+        let elsewhere = String::from("x");
+        assert_eq!(location(Error::unexpected(&elsewhere, "a number").locate(input)), None);
+        assert_eq!(location(Error::unexpected(&input[2..], "a number").locate(&input[..2])), None);
+        assert_eq!(location(Error::parse("a grid").locate(input)), None);
+    }
+}
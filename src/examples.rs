@@ -0,0 +1,150 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+use crate::answer::Answer;
+use crate::answers;
+use crate::html;
+use crate::input;
+use crate::scaffold;
+use crate::site::Client;
+
+/// The example of a puzzle description and the answers the description gives for it.
+#[derive(Debug, PartialEq)]
+pub struct Example {
+    pub input: String,
+    /// One answer per part described on the page, in part order.
+    pub answers: Vec<Answer>,
+}
+
+/// Reads the example out of a puzzle page.
+///
+/// Each part is an `<article class="day-desc">`. The input is the first `<pre><code>` block of
+/// part one, and a part's answer is the last emphasized code in its article, which is where the
+/// site states the example's result. Puzzles with several examples or a smaller follow-up
+/// example for part two need checking by hand.
+pub fn extract(page: &str) -> Option<Example> {
+    let article_re = Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+    let block_re = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let answer_re = Regex::new(r"(?s)<code><em>(.*?)</em></code>|<em><code>(.*?)</code></em>").unwrap();
+
+    let articles: Vec<&str> = article_re.captures_iter(page).map(|caps| caps.get(1).unwrap().as_str()).collect();
+    let block = block_re.captures(articles.first()?)?.get(1)?.as_str();
+    let answers = articles
+        .iter()
+        .map_while(|article| {
+            let caps = answer_re.captures_iter(article).last()?;
+            let answer = caps.get(1).or_else(|| caps.get(2))?.as_str();
+            Some(to_answer(html::text(answer).trim()))
+        })
+        .collect();
+    Some(Example { input: html::text(block), answers })
+}
+
+fn to_answer(text: &str) -> Answer {
+    if let Ok(value) = text.parse::<i64>() {
+        Answer::Int(value)
+    } else if let Ok(value) = text.parse::<u128>() {
+        Answer::BigInt(value)
+    } else {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// Writes `dayN.example.txt` and the expected answers, as `partN = value` lines like
+/// `answers.toml`, to `dayN.example.toml` in `dir`. Returns the files written.
+pub fn write(dir: &Path, day: u8, example: &Example) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+    let input_path = dir.join(format!("day{}.example.txt", day));
+    fs::write(&input_path, &example.input)?;
+
+    let mut expected = String::new();
+    for (part, answer) in (1..).zip(&example.answers) {
+        expected.push_str(&format!("part{} = {}\n", part, answers::format_value(answer)));
+    }
+    let answers_path = dir.join(format!("day{}.example.toml", day));
+    fs::write(&answers_path, expected)?;
+    Ok(vec![input_path, answers_path])
+}
+
+/// Extracts the example of `day` of `year` from the saved `page`, else from the site, into the
+/// year's source inputs, and points the day's untouched example test at it.
+pub fn examples(year: u16, day: u8, page: Option<&Path>) -> Result<(), String> {
+    let html = match page {
+        Some(path) => fs::read_to_string(path).map_err(|err| format!("{}: {}", path.display(), err))?,
+        None => Client::from_env()?.puzzle(year, day).map_err(|err| err.to_string())?,
+    };
+    let example = extract(&html).ok_or("found no example block in the puzzle description")?;
+
+    let year_dir = Path::new(input::SOURCE_DIR).join(format!("y{}", year));
+    for path in write(&year_dir.join("inputs"), day, &example).map_err(|err| err.to_string())? {
+        println!("wrote {}", path.display());
+    }
+    if example.answers.is_empty() {
+        println!("no example answer found; add the expected values by hand");
+        return Ok(());
+    }
+
+    let module = year_dir.join(format!("day{:02}.rs", day));
+    if !module.exists() {
+        return Ok(());
+    }
+    match scaffold::use_example(&module, day, &example.answers) {
+        Ok(true) => println!("updated the example test in {}", module.display()),
+        Ok(false) => println!("kept the example test in {}, which was edited by hand", module.display()),
+        Err(err) => return Err(format!("{}: {}", module.display(), err)),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<p>Pair up the <em>smallest</em> numbers; that is <code>1</code> and <code>3</code>.</p>
+<p>In the example above, this is a total distance of <code><em>11</em></code>!</p>
+</article>
+<p>Your puzzle answer was <code>1666427</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>So, for these example lists, the similarity score at the end of this process is <em><code>31</code></em>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn finds_the_input_and_each_parts_answer() {
+        let example = extract(PAGE).unwrap();
+        assert_eq!(example, Example { input: "3   4\n4   3\n".to_string(), answers: vec![Answer::Int(11), Answer::Int(31)] });
+
+        let part_one = &PAGE[..PAGE.find("<p>Your puzzle").unwrap()];
+        assert_eq!(extract(part_one).unwrap().answers, vec![Answer::Int(11)]);
+        assert_eq!(extract("<article class=\"day-desc\"><p>No example.</p></article>"), None);
+    }
+
+    #[test]
+    fn keeps_markup_characters_in_the_input() {
+        let page = "<article class=\"day-desc\"><pre><code>#.<em>&lt;</em>\n&amp;&gt;\n</code></pre>\
+                    <p>It prints <code><em>4,6,3,5</em></code>.</p></article>";
+        let example = extract(page).unwrap();
+        assert_eq!(example.input, "#.<\n&>\n");
+        assert_eq!(example.answers, vec![Answer::Text("4,6,3,5".to_string())]);
+    }
+
+    #[test]
+    fn writes_the_input_and_expected_answers() {
+        let dir = TempDir::new("examples");
+        let example = Example { input: "3   4\n".to_string(), answers: vec![Answer::Int(11), Answer::Text("a\nb".to_string())] };
+
+        write(&dir, 1, &example).unwrap();
+        assert_eq!(fs::read_to_string(dir.join("day1.example.txt")).unwrap(), "3   4\n");
+        assert_eq!(fs::read_to_string(dir.join("day1.example.toml")).unwrap(), "part1 = 11\npart2 = \"a\\nb\"\n");
+    }
+}
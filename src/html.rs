@@ -0,0 +1,31 @@
+/// The text of an HTML fragment: tags dropped and the entities the site uses decoded.
+///
+/// Whitespace is kept as it is, so `<pre>` blocks keep their lines.
+pub fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drops_tags_and_decodes_entities() {
+        assert_eq!(text("<code>#.<em>&lt;</em>\n&amp;.</code>"), "#.<\n&.");
+        assert_eq!(text("&amp;lt;"), "&lt;");
+    }
+}
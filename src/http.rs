@@ -0,0 +1,203 @@
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+/// Sent with every request, as the Advent of Code site asks automated clients to identify
+/// themselves: the crate's name, version and `repository` URL, put together by the build script.
+pub const USER_AGENT: &str = env!("AOC_USER_AGENT");
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+pub fn get(url: &str, headers: &[(&str, &str)]) -> io::Result<Response> {
+    request("GET", url, headers, None)
+}
+
+/// Sends `form` as `application/x-www-form-urlencoded`; see [`form_encode`].
+pub fn post_form(url: &str, headers: &[(&str, &str)], form: &[(&str, &str)]) -> io::Result<Response> {
+    let form: Vec<String> = form.iter().map(|(name, value)| format!("{}={}", form_encode(name), form_encode(value))).collect();
+    request("POST", url, headers, Some(&form.join("&")))
+}
+
+/// Percent-encodes `value` for a form body.
+fn form_encode(value: &str) -> String {
+    let mut encoded = String::new();
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+            b' ' => encoded.push('+'),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Plain `http://` URLs, such as a local test server, are handled here; `https://` ones go
+/// through `curl`, since the standard library has no TLS.
+fn request(method: &str, url: &str, headers: &[(&str, &str)], form: Option<&str>) -> io::Result<Response> {
+    if let Some(rest) = url.strip_prefix("http://") {
+        plain_request(method, rest, headers, form)
+    } else if url.starts_with("https://") {
+        curl_request(method, url, headers, form)
+    } else {
+        Err(io::Error::new(io::ErrorKind::InvalidInput, format!("unsupported URL '{}'", url)))
+    }
+}
+
+fn plain_request(method: &str, url: &str, headers: &[(&str, &str)], form: Option<&str>) -> io::Result<Response> {
+    let (authority, path) = match url.find('/') {
+        Some(slash) => (&url[..slash], &url[slash..]),
+        None => (url, "/"),
+    };
+    let address = if authority.contains(':') { authority.to_string() } else { format!("{}:80", authority) };
+
+    let mut stream = TcpStream::connect(&address)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    let mut request = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: {}\r\nConnection: close\r\n",
+        method, path, authority, USER_AGENT
+    );
+    for (name, value) in headers {
+        request.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    if let Some(form) = form {
+        request.push_str("Content-Type: application/x-www-form-urlencoded\r\n");
+        request.push_str(&format!("Content-Length: {}\r\n", form.len()));
+    }
+    request.push_str("\r\n");
+    request.push_str(form.unwrap_or(""));
+    stream.write_all(request.as_bytes())?;
+
+    read_response(BufReader::new(stream))
+}
+
+fn read_response(mut reader: impl BufRead) -> io::Result<Response> {
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_string());
+
+    let mut status_line = String::new();
+    reader.read_line(&mut status_line)?;
+    let status = status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse().ok())
+        .ok_or_else(|| invalid("malformed HTTP status line"))?;
+
+    let mut length = None;
+    let mut chunked = false;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(invalid("connection closed in the HTTP headers"));
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            let value = value.trim();
+            if name.eq_ignore_ascii_case("content-length") {
+                length = Some(value.parse::<u64>().map_err(|_| invalid("malformed Content-Length"))?);
+            } else if name.eq_ignore_ascii_case("transfer-encoding") {
+                chunked = value.eq_ignore_ascii_case("chunked");
+            }
+        }
+    }
+
+    let mut body = Vec::new();
+    if chunked {
+        loop {
+            let mut size = String::new();
+            reader.read_line(&mut size)?;
+            let size = size.trim().split(';').next().unwrap_or("");
+            let size = usize::from_str_radix(size, 16).map_err(|_| invalid("malformed chunk size"))?;
+            if size == 0 {
+                break;
+            }
+            let start = body.len();
+            body.resize(start + size, 0);
+            reader.read_exact(&mut body[start..])?;
+            reader.read_line(&mut String::new())?;
+        }
+    } else if let Some(length) = length {
+        reader.take(length).read_to_end(&mut body)?;
+    } else {
+        reader.read_to_end(&mut body)?;
+    }
+
+    let body = String::from_utf8(body).map_err(|_| invalid("response body is not UTF-8"))?;
+    Ok(Response { status, body })
+}
+
+fn curl_request(method: &str, url: &str, headers: &[(&str, &str)], form: Option<&str>) -> io::Result<Response> {
+    let mut command = Command::new("curl");
+    command
+        .args(["--silent", "--show-error", "--max-time", &TIMEOUT.as_secs().to_string()])
+        .args(["--request", method, "--user-agent", USER_AGENT])
+        // Headers such as the session cookie go through stdin, not the visible command line
+        .args(["--header", "@-"])
+        .args(["--write-out", "\n%{http_code}"])
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Some(form) = form {
+        command.args(["--data-raw", form]);
+    }
+
+    let mut child = command
+        .spawn()
+        .map_err(|err| io::Error::new(err.kind(), format!("could not start curl for an https URL: {}", err)))?;
+    {
+        let mut stdin = child.stdin.take().expect("curl stdin is piped");
+        for (name, value) in headers {
+            writeln!(stdin, "{}: {}", name, value)?;
+        }
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        let message = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(io::Error::other(format!("curl failed: {}", message)));
+    }
+
+    let output = String::from_utf8(output.stdout)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "response body is not UTF-8"))?;
+    let (body, status) = output.rsplit_once('\n').unwrap_or(("", &output));
+    let status = status
+        .trim()
+        .parse()
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "curl did not report an HTTP status"))?;
+    Ok(Response { status, body: body.to_string() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_sized_and_chunked_bodies() {
+        let sized = "HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nhello, and more";
+        assert_eq!(read_response(sized.as_bytes()).unwrap(), Response { status: 200, body: "hello".into() });
+
+        let chunked = "HTTP/1.1 404 Not Found\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nnot \r\n5\r\nfound\r\n0\r\n\r\n";
+        assert_eq!(read_response(chunked.as_bytes()).unwrap(), Response { status: 404, body: "not found".into() });
+    }
+
+    #[test]
+    fn encodes_form_values() {
+        assert_eq!(form_encode("abc-123"), "abc-123");
+        assert_eq!(form_encode("6,2 & 1"), "6%2C2+%26+1");
+    }
+
+    #[test]
+    fn rejects_other_schemes() {
+        assert_eq!(get("ftp://example.com/", &[]).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,294 @@
+use std::env;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::config;
+
+use flate2::read::GzDecoder;
+use zip::ZipArchive;
+
+/// Environment variable naming a directory that holds the puzzle inputs, one folder per year.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where the year folders (`y2024`, ...) live in this repository, independent of the working
+/// directory; each keeps its inputs in an `inputs` folder.
+pub const SOURCE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// Bundle of inputs looked for in each input directory.
+const ARCHIVE_NAME: &str = "inputs.zip";
+
+/// Where an input was read from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Origin {
+    /// A plain or gzip-compressed (`.gz`) file.
+    File(PathBuf),
+    /// A member of a zip archive.
+    Archive { archive: PathBuf, member: String },
+    /// Compiled into the binary with the `embed-inputs` feature.
+    Embedded { year: u16, day: u8 },
+    Stdin,
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Origin::File(path) => write!(f, "{}", path.display()),
+            Origin::Archive { archive, member } => write!(f, "{}:{}", archive.display(), member),
+            Origin::Embedded { year, day } => write!(f, "embedded:{}/day{}", year, day),
+            Origin::Stdin => write!(f, "-"),
+        }
+    }
+}
+
+impl Origin {
+    /// How to name the input in diagnostics; unlike `Display`, stdin is spelled out.
+    pub fn label(&self) -> String {
+        match self {
+            Origin::Stdin => "<stdin>".to_string(),
+            other => other.to_string(),
+        }
+    }
+}
+
+/// A puzzle input and where it came from.
+pub struct Input {
+    pub text: String,
+    pub origin: Origin,
+}
+
+/// Reads the input for `day` of `year`.
+///
+/// An explicit path wins, with `-` meaning stdin; an explicit `.zip` has the day's file taken
+/// out of it. Otherwise the day's file is looked up in `$AOC_INPUT_DIR/<year>` (or the
+/// configured `input_dir/<year>` when the variable is unset), then in the repository's
+/// `src/y<year>/inputs`, and finally among the embedded inputs when the `embed-inputs` feature
+/// is on. Files ending in `.gz` are decompressed transparently.
+pub fn load(year: u16, day: u8, explicit: Option<&Path>) -> io::Result<Input> {
+    let origin = match explicit {
+        Some(path) if path == Path::new("-") => Origin::Stdin,
+        Some(path) if has_extension(path, "zip") => find_in_archive(path, day)?.ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, format!("no input for day {} in {}", day, path.display()))
+        })?,
+        Some(path) => Origin::File(path.to_path_buf()),
+        None => resolve(year, day)?,
+    };
+
+    let text = read(&origin).map_err(|err| io::Error::new(err.kind(), format!("{}: {}", origin, err)))?;
+    Ok(Input { text, origin })
+}
+
+/// Finds the input for `day` of `year` in the configured input directories.
+///
+/// In each directory a loose file (plain or `.gz`) is preferred over a member of `inputs.zip`.
+pub fn resolve(year: u16, day: u8) -> io::Result<Origin> {
+    resolve_in(&input_dirs(year), year, day)
+}
+
+fn resolve_in(dirs: &[PathBuf], year: u16, day: u8) -> io::Result<Origin> {
+    for dir in dirs {
+        for name in file_names(day) {
+            for candidate in [dir.join(&name), dir.join(format!("{}.gz", name))] {
+                if candidate.is_file() {
+                    return Ok(Origin::File(candidate));
+                }
+            }
+        }
+
+        let archive = dir.join(ARCHIVE_NAME);
+        if archive.is_file() {
+            if let Some(origin) = find_in_archive(&archive, day)? {
+                return Ok(origin);
+            }
+        }
+    }
+
+    if embedded(year, day).is_some() {
+        return Ok(Origin::Embedded { year, day });
+    }
+
+    let searched: Vec<String> = dirs.iter().map(|dir| dir.display().to_string()).collect();
+    Err(io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("no input for {} day {} in {}", year, day, searched.join(", ")),
+    ))
+}
+
+fn read(origin: &Origin) -> io::Result<String> {
+    let mut text = String::new();
+    match origin {
+        Origin::File(path) if has_extension(path, "gz") => {
+            GzDecoder::new(File::open(path)?).read_to_string(&mut text)?;
+        }
+        Origin::File(path) => text = fs::read_to_string(path)?,
+        Origin::Archive { archive, member } => {
+            let mut archive = open_archive(archive)?;
+            let mut file = archive.by_name(member).map_err(zip_error)?;
+            file.read_to_string(&mut text)?;
+        }
+        Origin::Embedded { year, day } => {
+            text = embedded(*year, *day).ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?.to_string();
+        }
+        Origin::Stdin => {
+            io::stdin().read_to_string(&mut text)?;
+        }
+    }
+    Ok(text)
+}
+
+#[cfg(feature = "embed-inputs")]
+mod embedded {
+    include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs"));
+}
+
+#[cfg(feature = "embed-inputs")]
+fn embedded(year: u16, day: u8) -> Option<&'static str> {
+    embedded::EMBEDDED
+        .iter()
+        .find(|(embedded_year, embedded_day, _)| (*embedded_year, *embedded_day) == (year, day))
+        .map(|(_, _, text)| *text)
+}
+
+#[cfg(not(feature = "embed-inputs"))]
+fn embedded(_year: u16, _day: u8) -> Option<&'static str> {
+    None
+}
+
+/// Looks for the day's file in a zip archive, at its root or in any folder inside it.
+fn find_in_archive(archive: &Path, day: u8) -> io::Result<Option<Origin>> {
+    let zip = open_archive(archive)?;
+    let names = file_names(day);
+    let member = names.iter().find_map(|name| {
+        zip.file_names()
+            .find(|member| member.rsplit('/').next() == Some(name.as_str()))
+            .map(str::to_string)
+    });
+
+    Ok(member.map(|member| Origin::Archive { archive: archive.to_path_buf(), member }))
+}
+
+fn open_archive(path: &Path) -> io::Result<ZipArchive<File>> {
+    ZipArchive::new(File::open(path)?).map_err(zip_error)
+}
+
+fn zip_error(err: zip::result::ZipError) -> io::Error {
+    match err {
+        zip::result::ZipError::Io(err) => err,
+        other => io::Error::new(io::ErrorKind::InvalidData, other),
+    }
+}
+
+fn has_extension(path: &Path, extension: &str) -> bool {
+    path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case(extension))
+}
+
+fn input_dirs(year: u16) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => dirs.push(PathBuf::from(dir).join(year.to_string())),
+        None => dirs.extend(config::get().input_dir.as_ref().map(|dir| dir.join(year.to_string()))),
+    }
+    dirs.push(Path::new(SOURCE_DIR).join(format!("y{}", year)).join("inputs"));
+    dirs
+}
+
+/// Where downloaded inputs of `year` are saved: the first directory that inputs are read from.
+pub fn download_dir(year: u16) -> PathBuf {
+    input_dirs(year).remove(0)
+}
+
+/// The file names an input may have, e.g. `day1.txt`, `day01.txt`, `day1` and `day01`.
+pub fn file_names(day: u8) -> Vec<String> {
+    let mut names = Vec::new();
+    for stem in [format!("day{}", day), format!("day{:02}", day)] {
+        for name in [format!("{}.txt", stem), stem] {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    fn write_gz(path: &Path, text: &str) {
+        let mut encoder = GzEncoder::new(File::create(path).unwrap(), Compression::default());
+        encoder.write_all(text.as_bytes()).unwrap();
+        encoder.finish().unwrap();
+    }
+
+    /// Writes an `inputs.zip` holding `day01` inside a `2024` folder, as exported bundles do.
+    fn write_zip(dir: &Path, text: &str) -> PathBuf {
+        let path = dir.join(ARCHIVE_NAME);
+        let mut zip = ZipWriter::new(File::create(&path).unwrap());
+        let options = FileOptions::default().compression_method(zip::CompressionMethod::Stored);
+        zip.add_directory("2024/", options).unwrap();
+        zip.start_file("2024/day01", options).unwrap();
+        zip.write_all(text.as_bytes()).unwrap();
+        zip.finish().unwrap();
+        path
+    }
+
+    #[test]
+    fn reads_gzipped_files_and_archive_members() {
+        let dir = TempDir::new("input-formats");
+        let gz = dir.join("day1.txt.gz");
+        write_gz(&gz, "3   4\n");
+        let input = load(2024, 1, Some(&gz)).unwrap();
+        assert_eq!((input.text.as_str(), input.origin), ("3   4\n", Origin::File(gz)));
+
+        let archive = write_zip(&dir, "7 6 4 2 1\n");
+        let input = load(2024, 1, Some(&archive)).unwrap();
+        assert_eq!(input.text, "7 6 4 2 1\n");
+        assert_eq!(input.origin, Origin::Archive { archive: archive.clone(), member: "2024/day01".to_string() });
+        assert!(load(2024, 2, Some(&archive)).is_err_and(|err| err.kind() == io::ErrorKind::NotFound));
+    }
+
+    #[test]
+    fn loose_files_win_over_archives_and_earlier_directories_over_later_ones() {
+        let (first, second) = (TempDir::new("input-first"), TempDir::new("input-second"));
+        let dirs = [first.to_path_buf(), second.to_path_buf()];
+        fs::write(second.join("day01.txt"), "second").unwrap();
+        assert_eq!(resolve_in(&dirs, 2024, 1).unwrap(), Origin::File(second.join("day01.txt")));
+
+        let archive = write_zip(&first, "zipped");
+        assert_eq!(
+            resolve_in(&dirs, 2024, 1).unwrap(),
+            Origin::Archive { archive, member: "2024/day01".to_string() }
+        );
+
+        write_gz(&first.join("day1.gz"), "loose");
+        assert_eq!(resolve_in(&dirs, 2024, 1).unwrap(), Origin::File(first.join("day1.gz")));
+        fs::write(first.join("day1.txt"), "loose").unwrap();
+        assert_eq!(resolve_in(&dirs, 2024, 1).unwrap(), Origin::File(first.join("day1.txt")));
+    }
+
+    #[test]
+    fn falls_back_to_embedded_inputs() {
+        let dir = TempDir::new("input-empty");
+        let result = resolve_in(&[dir.to_path_buf()], 2024, 1);
+        if cfg!(feature = "embed-inputs") {
+            assert_eq!(result.unwrap(), Origin::Embedded { year: 2024, day: 1 });
+        } else {
+            let err = result.unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::NotFound);
+            assert!(err.to_string().contains(&dir.display().to_string()), "{}", err);
+        }
+    }
+
+    #[test]
+    fn accepts_padded_and_bare_names() {
+        assert_eq!(file_names(1), ["day1.txt", "day1", "day01.txt", "day01"]);
+        assert_eq!(file_names(12), ["day12.txt", "day12"]);
+    }
+}
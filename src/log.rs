@@ -0,0 +1,113 @@
+use std::env;
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Environment variable setting the log level: `error`, `warn`, `info`, `debug` or `trace`.
+pub const LOG_VAR: &str = "AOC_LOG";
+
+/// How much is logged, from only errors to every step of a solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 5] = [Level::Error, Level::Warn, Level::Info, Level::Debug, Level::Trace];
+
+    pub fn parse(name: &str) -> Option<Level> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "error" => Some(Level::Error),
+            "warn" | "warning" => Some(Level::Warn),
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            "trace" => Some(Level::Trace),
+            _ => None,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warn => "warning",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+
+    /// This level raised by `steps`, stopping at `Trace`.
+    fn raised(self, steps: u8) -> Level {
+        Level::ALL[(self as usize + steps as usize).min(Level::Trace as usize)]
+    }
+}
+
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Info as u8);
+
+/// Sets the level from `$AOC_LOG`, `info` when unset, raised once per `-v` flag.
+pub fn init(verbosity: u8) {
+    let base = match env::var(LOG_VAR) {
+        Ok(name) => Level::parse(&name).unwrap_or_else(|| {
+            log(Level::Warn, format_args!("ignoring unknown {} level '{}'", LOG_VAR, name));
+            Level::Info
+        }),
+        Err(_) => Level::Info,
+    };
+    MAX_LEVEL.store(base.raised(verbosity) as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+}
+
+/// Writes `args` to stderr when `level` is enabled; use the macros instead.
+pub fn log(level: Level, args: fmt::Arguments) {
+    if enabled(level) {
+        eprintln!("{}: {}", level.label(), args);
+    }
+}
+
+macro_rules! error {
+    ($($arg:tt)*) => { $crate::log::log($crate::log::Level::Error, format_args!($($arg)*)) };
+}
+
+macro_rules! warning {
+    ($($arg:tt)*) => { $crate::log::log($crate::log::Level::Warn, format_args!($($arg)*)) };
+}
+
+macro_rules! info {
+    ($($arg:tt)*) => { $crate::log::log($crate::log::Level::Info, format_args!($($arg)*)) };
+}
+
+macro_rules! debug {
+    ($($arg:tt)*) => { $crate::log::log($crate::log::Level::Debug, format_args!($($arg)*)) };
+}
+
+macro_rules! trace {
+    ($($arg:tt)*) => { $crate::log::log($crate::log::Level::Trace, format_args!($($arg)*)) };
+}
+
+pub(crate) use {debug, error, info, trace, warning};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_level_names() {
+        assert_eq!(Level::parse("debug"), Some(Level::Debug));
+        assert_eq!(Level::parse(" TRACE\n"), Some(Level::Trace));
+        assert_eq!(Level::parse("warn"), Level::parse("warning"));
+        assert_eq!(Level::parse("loud"), None);
+    }
+
+    #[test]
+    fn each_flag_raises_the_level_up_to_trace() {
+        assert_eq!(Level::Info.raised(0), Level::Info);
+        assert_eq!(Level::Info.raised(1), Level::Debug);
+        assert_eq!(Level::Warn.raised(5), Level::Trace);
+    }
+}
@@ -1,46 +1,151 @@
-use std::io::{self, Write};
+use std::env;
+use std::path::Path;
+use std::process;
 
-mod days;
+mod answer;
+mod answers;
+mod bench;
+mod cancel;
+mod cli;
+mod config;
+mod error;
+mod examples;
+mod html;
+mod http;
+mod input;
+mod json;
+mod log;
+mod params;
+mod repl;
+mod runner;
+mod scaffold;
+mod site;
+mod solution;
+mod status;
+mod submit;
+#[cfg(test)]
+mod testing;
+mod watch;
+mod y2024;
+mod years;
+
+use cli::{Command, Format};
+use solution::Entry;
 
 fn main() {
-    println!("Advent of Code!");
-
-    // Prompt the user for input
-    print!("Enter the day you want to run (1-25): ");
-    io::stdout().flush().unwrap();
-
-    let mut input = String::new();
-    io::stdin().read_line(&mut input).expect("Failed to read input");
-    let day = input.trim();
-
-    // Match user input to run corresponding day
-    match day {
-        "1" => days::day01::run(),
-        "2" => days::day02::run(),
-        "3" => days::day03::run(),
-        "4" => days::day04::run(),
-        "5" => days::day05::run(),
-        "6" => days::day06::run(),
-        "7" => days::day07::run(),
-        "8" => days::day08::run(),
-        "9" => days::day09::run(),
-        "10" => days::day10::run(),
-        "11" => days::day11::run(),
-        "12" => days::day12::run(),
-        "13" => days::day13::run(),
-        "14" => days::day14::run(),
-        "14p2" => days::day14p2::run(),
-        "15" => days::day15::run(),
-        "16" => days::day16::run(),
-        "17" => days::day17::run(),
-        "18" => days::day18::run(),
-        "19" => days::day19::run(),
-        "20" => days::day20::run(),
-        "21" => days::day21::run(),
-        "22" => days::day22::run(),
-        "23" => days::day23::run(),
-        "24" => days::day24::run(),
-        "25" => days::day25::run(),
-        _ => println!("Invalid day or not yet implemented!"),
+    let (args, verbosity) = cli::take_verbosity(env::args().skip(1));
+    log::init(verbosity);
+    if let Err(message) = config::init() {
+        log::error!("{}", message);
+        process::exit(2);
+    }
+
+    let command = match cli::parse(args) {
+        Ok(command) => command,
+        Err(message) => {
+            log::error!("{}\n\n{}", message, cli::USAGE);
+            process::exit(2);
+        }
+    };
+
+    match command {
+        Command::Run { year, day, part, input, format, timeout, params, record } => {
+            let entry = with_params(find_day(year, &day), &params);
+            let format = format_or_default(format);
+            params::with_overrides(&params, || runner::run(entry, part, input.as_deref(), format, timeout, record))
+        }
+        Command::All { year, format, timeout, record } => {
+            runner::run_all(year.map(check_year), format_or_default(format), timeout, record)
+        }
+        Command::Bench { year, day, runs, input, params } => {
+            let entry = with_params(find_day(year, &day), &params);
+            if let Err(err) = params::with_overrides(&params, || bench::bench(entry, runs, input.as_deref())) {
+                log::error!("{}", err);
+                process::exit(1);
+            }
+        }
+        Command::List { year } => runner::list(year.map(check_year)),
+        Command::Status { year } => status::status(year.map(check_year)),
+        Command::New { year, day, title } => {
+            let year = year.unwrap_or_else(years::default_year);
+            match scaffold::new_day(Path::new(input::SOURCE_DIR), year, day, title.as_deref()) {
+                Ok(paths) => {
+                    for path in paths {
+                        println!("wrote {}", path.display());
+                    }
+                }
+                Err(err) => {
+                    log::error!("{}", err);
+                    process::exit(1);
+                }
+            }
+        }
+        Command::Watch { year, day, part, input } => {
+            if let Err(err) = watch::watch(find_day(year, &day), part, input.as_deref()) {
+                log::error!("{}", err);
+                process::exit(1);
+            }
+        }
+        Command::Fetch { year, day } => {
+            let year = year.unwrap_or_else(years::default_year);
+            let client = site::Client::from_env().unwrap_or_else(|message| {
+                log::error!("{}", message);
+                process::exit(1);
+            });
+            match site::fetch(&client, year, day, &input::download_dir(year)) {
+                Ok(site::Fetched::Downloaded(path)) => println!("wrote {}", path.display()),
+                Ok(site::Fetched::Cached(path)) => println!("{} is already there", path.display()),
+                Err(err) => {
+                    log::error!("{}", err);
+                    process::exit(1);
+                }
+            }
+        }
+        Command::Submit { year, day, part } => {
+            if let Err(message) = submit::submit_day(find_day(year, &day), part) {
+                log::error!("{}", message);
+                process::exit(1);
+            }
+        }
+        Command::Examples { year, day, page } => {
+            let year = year.unwrap_or_else(years::default_year);
+            if let Err(message) = examples::examples(year, day, page.as_deref()) {
+                log::error!("{}", message);
+                process::exit(1);
+            }
+        }
+        Command::Help => println!("{}", cli::USAGE),
+        Command::Repl => repl::repl(),
+    }
+}
+
+/// Looks up `day` in `year`, or in the default year when none is given.
+fn find_day(year: Option<u16>, day: &str) -> &'static Entry {
+    let year = check_year(year.unwrap_or_else(years::default_year));
+    years::find(year, day).unwrap_or_else(|| {
+        log::error!("no solution registered for {} day '{}'", year, day);
+        process::exit(1);
+    })
+}
+
+/// Exits unless every override names one of `entry`'s parameters.
+fn with_params<'a>(entry: &'a Entry, overrides: &[(String, u64)]) -> &'a Entry {
+    if let Err(message) = params::validate(entry.params, overrides) {
+        log::error!("day {}: {}", entry.meta.key(), message);
+        process::exit(2);
+    }
+    entry
+}
+
+/// The format given on the command line, else the configured one, else text.
+fn format_or_default(format: Option<Format>) -> Format {
+    format.or(config::get().format).unwrap_or(Format::Text)
+}
+
+fn check_year(year: u16) -> u16 {
+    if years::registry(year).is_none() {
+        log::error!("no solutions registered for {}", year);
+        process::exit(1);
     }
+    year
 }
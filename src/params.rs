@@ -0,0 +1,120 @@
+use std::cell::RefCell;
+
+use crate::error::{Error, Result};
+
+/// A named puzzle constant, such as a grid size, that `--param NAME=VALUE` can override.
+///
+/// Example inputs usually need smaller values than the real puzzle.
+#[derive(Debug, Clone, Copy)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: u64,
+    /// Smallest value an override may take.
+    pub min: u64,
+    /// Largest value an override may take.
+    pub max: u64,
+    pub help: &'static str,
+}
+
+impl Param {
+    pub const fn new(name: &'static str, default: u64, help: &'static str) -> Self {
+        Param { name, default, min: 0, max: u64::MAX, help }
+    }
+
+    /// Limits overrides to `min..=max`, e.g. to keep a grid size above zero.
+    pub const fn between(self, min: u64, max: u64) -> Self {
+        Param { min, max, ..self }
+    }
+
+    /// The value given for this run with [`with_overrides`], or the default.
+    pub fn value(&self) -> u64 {
+        OVERRIDES.with(|overrides| {
+            overrides.borrow().iter().find(|(name, _)| name == self.name).map_or(self.default, |&(_, value)| value)
+        })
+    }
+
+    /// [`Param::value`] as the type the solution computes with.
+    pub fn get<T: TryFrom<u64>>(&self) -> Result<T> {
+        let value = self.value();
+        T::try_from(value).map_err(|_| Error::puzzle(format!("parameter '{}' is too large: {}", self.name, value)))
+    }
+}
+
+thread_local! {
+    /// The overrides of the run on this thread, installed by [`with_overrides`].
+    static OVERRIDES: RefCell<Vec<(String, u64)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Runs `f` with `overrides` taking the place of the parameters' defaults.
+pub fn with_overrides<T>(overrides: &[(String, u64)], f: impl FnOnce() -> T) -> T {
+    // Restores the previous overrides even if `f` panics
+    struct Installed(Vec<(String, u64)>);
+    impl Drop for Installed {
+        fn drop(&mut self) {
+            OVERRIDES.with(|current| *current.borrow_mut() = std::mem::take(&mut self.0));
+        }
+    }
+    let _installed = Installed(OVERRIDES.with(|current| current.replace(overrides.to_vec())));
+    f()
+}
+
+/// Whether the run on this thread overrides any parameter, so its answers are not the puzzle's.
+pub fn overridden() -> bool {
+    OVERRIDES.with(|overrides| !overrides.borrow().is_empty())
+}
+
+/// Checks that every override names one of `params` and is within its bounds.
+pub fn validate(params: &[Param], overrides: &[(String, u64)]) -> std::result::Result<(), String> {
+    for (name, value) in overrides {
+        let Some(param) = params.iter().find(|param| param.name == name) else {
+            let known: Vec<&str> = params.iter().map(|param| param.name).collect();
+            return Err(match known.as_slice() {
+                [] => format!("unknown parameter '{}', this day has none", name),
+                _ => format!("unknown parameter '{}', expected one of: {}", name, known.join(", ")),
+            });
+        };
+        if !(param.min..=param.max).contains(value) {
+            return Err(match param.max {
+                u64::MAX => format!("parameter '{}' must be at least {}, got {}", name, param.min, value),
+                max => format!("parameter '{}' must be between {} and {}, got {}", name, param.min, max, value),
+            });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIDTH: Param = Param::new("width", 101, "width of the room").between(1, 1000);
+
+    #[test]
+    fn overrides_apply_only_inside_the_run() {
+        assert_eq!(WIDTH.value(), 101);
+        let inside = with_overrides(&[("width".to_string(), 11)], || (WIDTH.value(), overridden()));
+        assert_eq!(inside, (11, true));
+        assert_eq!((WIDTH.value(), overridden()), (101, false));
+    }
+
+    #[test]
+    fn rejects_unknown_names() {
+        assert!(validate(&[WIDTH], &[("width".to_string(), 11)]).is_ok());
+        let err = validate(&[WIDTH], &[("height".to_string(), 7)]).unwrap_err();
+        assert!(err.contains("expected one of: width"), "{}", err);
+    }
+
+    #[test]
+    fn rejects_values_out_of_bounds() {
+        let err = validate(&[WIDTH], &[("width".to_string(), 0)]).unwrap_err();
+        assert_eq!(err, "parameter 'width' must be between 1 and 1000, got 0");
+        assert!(validate(&[WIDTH], &[("width".to_string(), 1000)]).is_ok());
+    }
+
+    #[test]
+    fn converts_without_truncating() {
+        let large = Param::new("large", u64::from(u32::MAX) + 1, "");
+        assert_eq!(large.get::<u64>().unwrap(), 1 << 32);
+        assert!(large.get::<u32>().is_err());
+    }
+}
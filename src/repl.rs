@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use crate::bench;
+use crate::cli::parse_part;
+use crate::input;
+use crate::log;
+use crate::runner::{self, Checking};
+use crate::solution::{Entry, Puzzle};
+use crate::years;
+
+const HELP: &str = "\
+Commands:
+  run <DAY> [1|2]        Run a day, or one of its parts (a bare DAY does the same)
+  bench <DAY> [RUNS]     Time parsing and each part over RUNS runs (default 10)
+  input <DAY> [PATH]     Read the day's input from PATH from now on; without PATH, go back
+                         to the default lookup
+  year [YEAR]            Show or switch the year DAY refers to
+  list                   List the registered days of the current year
+  help                   Show this message
+  quit                   Leave (so does end of input)
+
+Parsed inputs are kept between commands and reused while the input text is unchanged.";
+
+#[derive(Debug, PartialEq)]
+enum ReplCommand {
+    Run { day: String, part: Option<u8> },
+    Bench { day: String, runs: usize },
+    Input { day: String, path: Option<PathBuf> },
+    Year(Option<u16>),
+    List,
+    Help,
+    Quit,
+}
+
+/// A parsed input kept for the next command, with the text it was parsed from.
+struct Cached {
+    text: String,
+    puzzle: Box<dyn Puzzle>,
+}
+
+struct Repl {
+    year: u16,
+    /// Input overrides set with `input`, by (year, key).
+    inputs: HashMap<(u16, String), PathBuf>,
+    cache: HashMap<(u16, String), Cached>,
+}
+
+/// Reads commands from stdin until `quit` or end of input.
+pub fn repl() {
+    println!("Advent of Code! Type `help` for the commands.");
+    let mut repl = Repl { year: years::default_year(), inputs: HashMap::new(), cache: HashMap::new() };
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+
+    loop {
+        print!("{}> ", repl.year);
+        if io::stdout().flush().is_err() {
+            break;
+        }
+
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            Some(Err(err)) => {
+                log::error!("{}", err);
+                break;
+            }
+            None => break,
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match parse_command(&line) {
+            Ok(ReplCommand::Quit) => break,
+            Ok(command) => repl.execute(command),
+            Err(message) => log::error!("{}", message),
+        }
+    }
+}
+
+impl Repl {
+    fn execute(&mut self, command: ReplCommand) {
+        match command {
+            ReplCommand::Run { day, part } => {
+                if let Some(entry) = self.find(&day) {
+                    self.run(entry, part);
+                }
+            }
+            ReplCommand::Bench { day, runs } => {
+                if let Some(entry) = self.find(&day) {
+                    let input = self.inputs.get(&(self.year, entry.meta.key())).cloned();
+                    if let Err(err) = bench::bench(entry, runs, input.as_deref()) {
+                        log::error!("{}", err);
+                    }
+                }
+            }
+            ReplCommand::Input { day, path } => {
+                if let Some(entry) = self.find(&day) {
+                    let key = (self.year, entry.meta.key());
+                    self.cache.remove(&key);
+                    match path {
+                        Some(path) => {
+                            println!("day {} now reads {}", entry.meta.key(), path.display());
+                            self.inputs.insert(key, path);
+                        }
+                        None => {
+                            println!("day {} now reads its default input", entry.meta.key());
+                            self.inputs.remove(&key);
+                        }
+                    }
+                }
+            }
+            ReplCommand::Year(None) => println!("{}", self.year),
+            ReplCommand::Year(Some(year)) => match years::registry(year) {
+                Some(_) => self.year = year,
+                None => log::error!("no solutions registered for {}", year),
+            },
+            ReplCommand::List => runner::list(Some(self.year)),
+            ReplCommand::Help => println!("{}", HELP),
+            ReplCommand::Quit => {}
+        }
+    }
+
+    fn find(&self, day: &str) -> Option<&'static Entry> {
+        let entry = years::find(self.year, day);
+        if entry.is_none() {
+            log::error!("no solution registered for {} day '{}'", self.year, day);
+        }
+        entry
+    }
+
+    /// Runs `entry`, parsing its input only when it is not cached or has changed on disk.
+    fn run(&mut self, entry: &'static Entry, part: Option<u8>) {
+        let key = (self.year, entry.meta.key());
+        let explicit = self.inputs.get(&key).cloned();
+        let input = match input::load(entry.meta.year, entry.meta.day, explicit.as_deref()) {
+            Ok(input) => input,
+            Err(err) => {
+                log::error!("{}", err);
+                return;
+            }
+        };
+
+        match self.cache.get(&key) {
+            Some(cached) if cached.text == input.text => log::info!("parsed input reused"),
+            _ => {
+                let start = Instant::now();
+                match runner::parse(entry, &input, None) {
+                    Ok(puzzle) => {
+                        log::info!("parsed in {:.3?}", start.elapsed());
+                        self.cache.insert(key.clone(), Cached { text: input.text, puzzle });
+                    }
+                    Err(status) => {
+                        self.cache.remove(&key);
+                        let outcomes = runner::select_parts(part)
+                            .into_iter()
+                            .map(|part| runner::failed(entry, part, &input.origin, status.clone()))
+                            .collect();
+                        runner::report(entry, outcomes, Checking::Off);
+                        return;
+                    }
+                }
+            }
+        }
+
+        let puzzle = self.cache[&key].puzzle.as_ref();
+        let outcomes = runner::solve(entry, puzzle, &runner::select_parts(part), &input.origin, None);
+        let checking = if explicit.is_none() { Checking::Verify } else { Checking::Off };
+        runner::report(entry, outcomes, checking);
+    }
+}
+
+fn parse_command(line: &str) -> Result<ReplCommand, String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let day = |index: usize| words.get(index).map(|day| day.to_string()).ok_or("missing day");
+    let command = match words.first().copied().unwrap_or_default() {
+        "run" | "r" => ReplCommand::Run { day: day(1)?, part: words.get(2).map(|part| parse_part(part)).transpose()? },
+        "bench" | "b" => ReplCommand::Bench {
+            day: day(1)?,
+            runs: match words.get(2) {
+                Some(runs) => runs.parse().ok().filter(|&runs| runs > 0).ok_or("runs must be a positive number")?,
+                None => 10,
+            },
+        },
+        "input" | "i" => ReplCommand::Input { day: day(1)?, path: words.get(2).map(|path| Path::new(path).to_path_buf()) },
+        "year" | "y" => ReplCommand::Year(
+            words.get(1).map(|year| year.parse().map_err(|_| format!("invalid year '{}'", year))).transpose()?,
+        ),
+        "list" | "ls" => ReplCommand::List,
+        "help" | "h" | "?" => ReplCommand::Help,
+        "quit" | "exit" | "q" => ReplCommand::Quit,
+        // A bare day, as the old one-shot prompt accepted
+        word if word.starts_with(|c: char| c.is_ascii_digit()) => ReplCommand::Run {
+            day: word.to_string(),
+            part: words.get(1).map(|part| parse_part(part)).transpose()?,
+        },
+        other => return Err(format!("unknown command '{}', try `help`", other)),
+    };
+    Ok(command)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_commands() {
+        assert_eq!(parse_command("run 6 2"), Ok(ReplCommand::Run { day: "6".into(), part: Some(2) }));
+        assert_eq!(parse_command("14p2"), Ok(ReplCommand::Run { day: "14p2".into(), part: None }));
+        assert_eq!(parse_command("bench 11"), Ok(ReplCommand::Bench { day: "11".into(), runs: 10 }));
+        assert_eq!(
+            parse_command("input 14 other.txt"),
+            Ok(ReplCommand::Input { day: "14".into(), path: Some(PathBuf::from("other.txt")) })
+        );
+        assert_eq!(parse_command("input 14"), Ok(ReplCommand::Input { day: "14".into(), path: None }));
+        assert_eq!(parse_command("quit"), Ok(ReplCommand::Quit));
+        assert!(parse_command("run 6 3").is_err());
+        assert!(parse_command("bench").is_err());
+        assert!(parse_command("frobnicate").is_err());
+    }
+}
@@ -0,0 +1,419 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::answer::Answer;
+use crate::answers::{AnswerBook, Verdict, ANSWERS_FILE};
+use crate::cancel;
+use crate::cli::Format;
+use crate::error::Error;
+use crate::input::{self, Input, Origin};
+use crate::log;
+use crate::params;
+use crate::json;
+use crate::solution::{Entry, Puzzle};
+use crate::years;
+
+/// How one part of a solution finished.
+#[derive(Clone)]
+pub enum Status {
+    Ok,
+    Panicked(String),
+    /// The input could not be loaded or parsed, or the part returned an error.
+    Failed(String),
+    Unimplemented,
+    /// Gave up after spending the time budget.
+    Timeout(Duration),
+}
+
+impl Status {
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Panicked(_) => "panicked",
+            Status::Failed(_) => "error",
+            Status::Unimplemented => "unimplemented",
+            Status::Timeout(_) => "timeout",
+        }
+    }
+}
+
+/// Result of running one part of a solution.
+pub struct Outcome {
+    pub year: u16,
+    pub key: String,
+    pub part: u8,
+    /// Where the input came from; `None` when it could not be loaded.
+    pub input: Option<Origin>,
+    pub answer: Option<Answer>,
+    pub elapsed: Duration,
+    pub status: Status,
+    /// Comparison with the recorded answer, when there is an answer and a book to check it in.
+    pub verdict: Option<Verdict>,
+}
+
+impl Outcome {
+    fn to_json(&self) -> String {
+        let answer = match &self.answer {
+            Some(Answer::Int(value)) if value.unsigned_abs() <= json::MAX_SAFE_INTEGER => value.to_string(),
+            // Larger numbers are quoted so consumers that read numbers as doubles keep every digit.
+            Some(Answer::Int(value)) => json::string(&value.to_string()),
+            Some(Answer::BigInt(value)) => json::string(&value.to_string()),
+            Some(Answer::Text(value)) => json::string(value),
+            None => "null".to_string(),
+        };
+        let error = match &self.status {
+            Status::Panicked(message) | Status::Failed(message) => Some(message.clone()),
+            Status::Timeout(budget) => Some(format!("timed out after {:?}", budget)),
+            _ => None,
+        };
+
+        json::Object::new()
+            .raw("year", self.year.to_string())
+            .str("day", &self.key)
+            .raw("part", self.part.to_string())
+            .str("status", self.status.label())
+            .raw("answer", answer)
+            .raw("elapsed_ms", format!("{:.3}", self.elapsed.as_secs_f64() * 1000.0))
+            .opt_str("input", self.input.as_ref().map(Origin::to_string).as_deref())
+            .opt_str("verdict", self.verdict.as_ref().map(verdict_label))
+            .opt_str("error", error.as_deref())
+            .finish()
+    }
+}
+
+/// What a run does with the answers recorded in `answers.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Checking {
+    /// Leave them out, e.g. when the input is not the puzzle's.
+    Off,
+    /// Compare each answer with the recorded one.
+    Verify,
+    /// Compare, and save NEW answers as the recorded ones.
+    Record,
+}
+
+/// Runs one solution and prints its answers.
+///
+/// `part` limits the run to a single part; `input` overrides the resolved input file.
+/// `timeout` is the budget for parsing and for each part; see `cancel::with_budget`.
+/// Answers are only verified against the recorded ones, and with `record` saved as them, when
+/// neither the input nor a parameter is overridden.
+pub fn run(entry: &Entry, part: Option<u8>, input: Option<&Path>, format: Format, timeout: Option<Duration>, record: bool) {
+    let parts = select_parts(part);
+    let checking = match (input.is_none() && !params::overridden(), record) {
+        (false, _) => Checking::Off,
+        (true, false) => Checking::Verify,
+        (true, true) => Checking::Record,
+    };
+    match format {
+        // The default hook's message and backtrace are useful when running a single day.
+        Format::Text => report(entry, run_parts(entry, &parts, input, timeout), checking),
+        Format::Json => {
+            let mut answers = if checking == Checking::Off { None } else { load_answers() };
+            let mut recorded = false;
+            for mut outcome in quiet_panics(|| run_parts(entry, &parts, input, timeout)) {
+                recorded |= verify(&mut outcome, answers.as_mut(), checking);
+                println!("{}", outcome.to_json());
+            }
+            if let (Some(book), true) = (answers, recorded) {
+                save_answers(&book);
+            }
+        }
+    }
+}
+
+/// Both parts, or only `part` when one is given.
+pub fn select_parts(part: Option<u8>) -> Vec<u8> {
+    [1, 2].into_iter().filter(|&current| part.is_none_or(|part| part == current)).collect()
+}
+
+/// Prints the outcomes of one solution, checking them against the recorded answers as asked.
+pub fn report(entry: &Entry, outcomes: Vec<Outcome>, checking: Checking) {
+    let mut answers = if checking == Checking::Off { None } else { load_answers() };
+    let mut recorded = false;
+    println!("Day {} - {}!!", entry.meta.key(), entry.meta.title);
+    for mut outcome in outcomes {
+        recorded |= verify(&mut outcome, answers.as_mut(), checking);
+        print_part(&outcome);
+    }
+    if let (Some(book), true) = (answers, recorded) {
+        save_answers(&book);
+    }
+}
+
+/// Runs every registered solution of `year`, or of every year, in turn and prints a summary table.
+///
+/// A panic or timeout in one day is recorded and the run moves on to the next day.
+///
+/// With `record`, NEW answers are saved as the recorded ones.
+pub fn run_all(year: Option<u16>, format: Format, timeout: Option<Duration>, record: bool) {
+    let checking = if record { Checking::Record } else { Checking::Verify };
+    let mut answers = load_answers();
+    let mut recorded = false;
+    if format == Format::Text {
+        println!("{:>4} {:>5} {:>4}  {:<20} {:>12}  {:<13}  check", "year", "day", "part", "answer", "time", "status");
+    }
+
+    // Panics are reported in the table, so silence the default hook's output meanwhile.
+    let outcomes = quiet_panics(|| {
+        let mut outcomes = Vec::new();
+        for entry in years::entries(year) {
+            for mut outcome in run_parts(entry, &[1, 2], None, timeout) {
+                recorded |= verify(&mut outcome, answers.as_mut(), checking);
+                match format {
+                    Format::Text => print_row(&outcome),
+                    Format::Json => println!("{}", outcome.to_json()),
+                }
+                outcomes.push(outcome);
+            }
+        }
+        outcomes
+    });
+
+    if let (Some(book), true) = (answers, recorded) {
+        save_answers(&book);
+    }
+    if format == Format::Text {
+        print_summary(&outcomes);
+    }
+}
+
+/// Prints the registered solutions of `year`, or of every year, with the parameters they take.
+pub fn list(year: Option<u16>) {
+    for entry in years::entries(year) {
+        let meta = &entry.meta;
+        println!("{:>4} {:>5}  {}", meta.year, meta.key(), meta.title);
+        for param in entry.params {
+            let setting = format!("{}={}", param.name, param.default);
+            println!("{:12}{:<26}  {}", "", setting, param.help);
+        }
+    }
+}
+
+/// Runs the given parts of `entry`, catching panics in parsing and in each part.
+pub fn run_parts(entry: &Entry, parts: &[u8], input: Option<&Path>, timeout: Option<Duration>) -> Vec<Outcome> {
+    let input = match input::load(entry.meta.year, entry.meta.day, input) {
+        Ok(input) => input,
+        Err(err) => {
+            return parts
+                .iter()
+                .map(|&part| outcome(entry, part, None, None, Duration::ZERO, Status::Failed(err.to_string())))
+                .collect();
+        }
+    };
+
+    let start = Instant::now();
+    let parsed = parse(entry, &input, timeout);
+    let elapsed = start.elapsed();
+    match parsed {
+        Ok(puzzle) => solve(entry, puzzle.as_ref(), parts, &input.origin, timeout),
+        Err(status) => parts
+            .iter()
+            .map(|&part| outcome(entry, part, Some(&input.origin), None, elapsed, status.clone()))
+            .collect(),
+    }
+}
+
+/// Parses `input` for `entry`, turning a parse error, timeout or panic into the status its parts get.
+pub fn parse(entry: &Entry, input: &Input, timeout: Option<Duration>) -> Result<Box<dyn Puzzle>, Status> {
+    match cancel::with_budget(timeout, || panic::catch_unwind(|| (entry.parse)(&input.text))) {
+        Ok(Ok(puzzle)) => Ok(puzzle),
+        Ok(Err(Error::Timeout)) => Err(Status::Timeout(timeout.unwrap_or_default())),
+        Ok(Err(err)) => Err(Status::Failed(err.in_file(input.origin.label()).to_string())),
+        Err(payload) => Err(Status::Panicked(panic_message(payload))),
+    }
+}
+
+/// Runs the given parts of an already parsed puzzle, catching panics in each part.
+pub fn solve(
+    entry: &Entry,
+    puzzle: &dyn Puzzle,
+    parts: &[u8],
+    origin: &Origin,
+    timeout: Option<Duration>,
+) -> Vec<Outcome> {
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = cancel::with_budget(timeout, || {
+                panic::catch_unwind(AssertUnwindSafe(|| if part == 1 { puzzle.part1() } else { puzzle.part2() }))
+            });
+            let elapsed = start.elapsed();
+            let (answer, status) = match answer {
+                Ok(Ok(answer)) => (Some(answer), Status::Ok),
+                Ok(Err(Error::Unimplemented)) => (None, Status::Unimplemented),
+                Ok(Err(Error::Timeout)) => (None, Status::Timeout(timeout.unwrap_or_default())),
+                Ok(Err(err)) => (None, Status::Failed(err.to_string())),
+                Err(payload) => (None, Status::Panicked(panic_message(payload))),
+            };
+            outcome(entry, part, Some(origin), answer, elapsed, status)
+        })
+        .collect()
+}
+
+/// The outcome of a part that could not run, e.g. because parsing failed.
+pub fn failed(entry: &Entry, part: u8, origin: &Origin, status: Status) -> Outcome {
+    outcome(entry, part, Some(origin), None, Duration::ZERO, status)
+}
+
+fn outcome(
+    entry: &Entry,
+    part: u8,
+    origin: Option<&Origin>,
+    answer: Option<Answer>,
+    elapsed: Duration,
+    status: Status,
+) -> Outcome {
+    Outcome {
+        year: entry.meta.year,
+        key: entry.meta.key(),
+        part,
+        input: origin.cloned(),
+        answer,
+        elapsed,
+        status,
+        verdict: None,
+    }
+}
+
+/// Fills in the outcome's verdict; returns whether its answer was recorded as a NEW one.
+fn verify(outcome: &mut Outcome, answers: Option<&mut AnswerBook>, checking: Checking) -> bool {
+    let (Some(answer), Some(book)) = (&outcome.answer, answers) else { return false };
+    let verdict = book.check(outcome.year, &outcome.key, outcome.part, answer);
+    let record = verdict == Verdict::New && checking == Checking::Record;
+    if record {
+        book.record(outcome.year, &outcome.key, outcome.part, answer);
+    }
+    outcome.verdict = Some(verdict);
+    record
+}
+
+fn print_part(outcome: &Outcome) {
+    match (&outcome.status, &outcome.answer, &outcome.verdict) {
+        (Status::Ok, Some(answer), Some(verdict)) => println!("Part {}: {} [{}]", outcome.part, answer, verdict),
+        (Status::Ok, Some(answer), None) => println!("Part {}: {}", outcome.part, answer),
+        (Status::Panicked(message), _, _) => println!("Part {}: panicked: {}", outcome.part, message),
+        (Status::Failed(message), _, _) => println!("Part {}: error: {}", outcome.part, message),
+        (Status::Timeout(budget), _, _) => println!("Part {}: TIMEOUT after {:?}", outcome.part, budget),
+        _ => println!("Part {}: not implemented yet", outcome.part),
+    }
+}
+
+fn print_row(outcome: &Outcome) {
+    println!(
+        "{:>4} {:>5} {:>4}  {:<20} {:>12}  {:<13}  {}",
+        outcome.year,
+        outcome.key,
+        outcome.part,
+        outcome.answer.as_ref().map_or("-".to_string(), Answer::to_string),
+        format!("{:.3?}", outcome.elapsed),
+        outcome.status.label(),
+        outcome.verdict.as_ref().map_or("-", verdict_label),
+    );
+}
+
+fn print_summary(outcomes: &[Outcome]) {
+    let total: Duration = outcomes.iter().map(|outcome| outcome.elapsed).sum();
+    let count = |status: fn(&Status) -> bool| outcomes.iter().filter(|outcome| status(&outcome.status)).count();
+    println!(
+        "\n{} ok, {} panicked, {} errors, {} timed out, {} unimplemented in {:.3?}",
+        count(|status| matches!(status, Status::Ok)),
+        count(|status| matches!(status, Status::Panicked(_))),
+        count(|status| matches!(status, Status::Failed(_))),
+        count(|status| matches!(status, Status::Timeout(_))),
+        count(|status| matches!(status, Status::Unimplemented)),
+        total,
+    );
+    for outcome in outcomes {
+        match &outcome.status {
+            Status::Panicked(message) => {
+                println!("{} day {} part {} panicked: {}", outcome.year, outcome.key, outcome.part, message)
+            }
+            Status::Failed(message) => {
+                println!("{} day {} part {} error: {}", outcome.year, outcome.key, outcome.part, message)
+            }
+            Status::Timeout(budget) => {
+                println!("{} day {} part {} TIMEOUT after {:?}", outcome.year, outcome.key, outcome.part, budget)
+            }
+            _ => {}
+        }
+        if let Some(verdict @ Verdict::Fail { .. }) = &outcome.verdict {
+            println!("{} day {} part {} {}", outcome.year, outcome.key, outcome.part, verdict);
+        }
+    }
+}
+
+fn verdict_label(verdict: &Verdict) -> &'static str {
+    match verdict {
+        Verdict::Pass => "PASS",
+        Verdict::Fail { .. } => "FAIL",
+        Verdict::New => "NEW",
+    }
+}
+
+/// Runs `f` with the panic hook silenced, for callers that report panics themselves.
+fn quiet_panics<T>(f: impl FnOnce() -> T) -> T {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = f();
+    panic::set_hook(default_hook);
+    result
+}
+
+fn load_answers() -> Option<AnswerBook> {
+    match AnswerBook::load(ANSWERS_FILE) {
+        Ok(book) => Some(book),
+        Err(err) => {
+            log::warning!("not verifying answers: {}", err);
+            None
+        }
+    }
+}
+
+fn save_answers(book: &AnswerBook) {
+    if let Err(err) = book.save() {
+        log::warning!("could not save {}: {}", book.path().display(), err);
+    }
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer_json(answer: Answer) -> String {
+        let outcome = Outcome {
+            year: 2024,
+            key: "13".to_string(),
+            part: 2,
+            input: None,
+            answer: Some(answer),
+            elapsed: Duration::ZERO,
+            status: Status::Ok,
+            verdict: None,
+        };
+        let json = outcome.to_json();
+        let start = json.find("\"answer\":").unwrap() + "\"answer\":".len();
+        json[start..json.find(",\"elapsed_ms\"").unwrap()].to_string()
+    }
+
+    #[test]
+    fn quotes_numbers_a_double_cannot_hold() {
+        assert_eq!(answer_json(Answer::Int(9_007_199_254_740_991)), "9007199254740991");
+        assert_eq!(answer_json(Answer::Int(-9_007_199_254_740_991)), "-9007199254740991");
+        assert_eq!(answer_json(Answer::Int(9_007_199_254_740_993)), "\"9007199254740993\"");
+        assert_eq!(answer_json(Answer::Int(i64::MIN)), "\"-9223372036854775808\"");
+        assert_eq!(answer_json(Answer::BigInt(1)), "\"1\"");
+    }
+}
@@ -0,0 +1,401 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::answer::Answer;
+use crate::input;
+
+/// Creates the skeleton for `day` of `year` under `src_dir` (the crate's `src`).
+///
+/// Writes `y<year>/dayNN.rs` with a `Solution` stub and a placeholder example test, an empty
+/// input file, and registers the module in `y<year>/mod.rs`. A year seen for the first time
+/// also gets its `mod.rs` and is registered in `main.rs` and `years.rs`. Nothing is written if
+/// the module already exists or any file to edit lacks the expected layout, and a failed write
+/// changes no file.
+///
+/// Returns the files created or changed.
+pub fn new_day(src_dir: &Path, year: u16, day: u8, title: Option<&str>) -> io::Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(invalid(format!("day must be between 1 and 25, got {}", day)));
+    }
+
+    let module = format!("day{:02}", day);
+    let year_dir = src_dir.join(format!("y{}", year));
+    let module_path = year_dir.join(format!("{}.rs", module));
+    if module_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", module_path.display()),
+        ));
+    }
+
+    // Work out every edit before touching the disk, so an unexpected layout changes nothing
+    let mut writes = Vec::new();
+    let mod_path = year_dir.join("mod.rs");
+    let registry = if mod_path.exists() { fs::read_to_string(&mod_path)? } else { year_module(year) };
+    let registry = register_day(&registry, &module)
+        .ok_or_else(|| invalid(format!("{} already registers {} or has no REGISTRY", mod_path.display(), module)))?;
+    if !mod_path.exists() {
+        for (path, edit) in [
+            (src_dir.join("main.rs"), register_year_module as fn(&str, u16) -> Option<String>),
+            (src_dir.join("years.rs"), register_year),
+        ] {
+            let contents = edit(&fs::read_to_string(&path)?, year)
+                .ok_or_else(|| invalid(format!("could not register {} in {}", year, path.display())))?;
+            writes.push((path, contents));
+        }
+    }
+    writes.push((mod_path, registry));
+    writes.push((module_path, day_module(year, day, title.unwrap_or(&format!("Day {}", day)))));
+
+    let input_dir = year_dir.join("inputs");
+    if !input::file_names(day).iter().any(|name| input_dir.join(name).exists()) {
+        writes.push((input_dir.join(format!("day{}.txt", day)), String::new()));
+    }
+
+    fs::create_dir_all(&input_dir)?;
+    commit(writes)
+}
+
+/// Writes each file next to its destination first and renames them all into place only once
+/// every write has succeeded, so a failed write leaves no file changed.
+fn commit(writes: Vec<(PathBuf, String)>) -> io::Result<Vec<PathBuf>> {
+    let staged: Vec<(PathBuf, PathBuf)> = writes
+        .iter()
+        .map(|(path, _)| (staging_path(path), path.clone()))
+        .collect();
+    for ((temp, _), (_, contents)) in staged.iter().zip(&writes) {
+        if let Err(err) = fs::write(temp, contents) {
+            for (temp, _) in &staged {
+                let _ = fs::remove_file(temp);
+            }
+            return Err(err);
+        }
+    }
+
+    let mut written = Vec::new();
+    for (temp, path) in staged {
+        fs::rename(&temp, &path)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// A hidden sibling of `path`, so that renaming it over `path` stays on one file system.
+fn staging_path(path: &Path) -> PathBuf {
+    let name = path.file_name().map_or_else(Default::default, |name| name.to_string_lossy());
+    path.with_file_name(format!(".{}.new", name))
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn day_module(year: u16, day: u8, title: &str) -> String {
+    let name = format!("Day{:02}", day);
+    format!(
+        "\
+use crate::error::Result;
+use crate::solution::{{Meta, Solution}};
+
+pub struct {name} {{}}
+
+impl Solution for {name} {{
+    const META: Meta = Meta {{
+        year: {year},
+        day: {day},
+        variant: None,
+        title: {title:?},
+    }};
+
+    fn parse(_input: &str) -> Result<Self> {{
+        Ok({name} {{}})
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    use crate::answer::Answer;
+
+{test}}}
+",
+        test = placeholder_test(&name)
+    )
+}
+
+/// The example test of a new module, ignored until the example is pasted in.
+fn placeholder_test(name: &str) -> String {
+    format!(
+        "    const EXAMPLE: &str = \"\\
+\";
+
+    #[test]
+    #[ignore = \"paste the example and its answer from the puzzle description\"]
+    fn example() {{
+        let day = {name}::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1().unwrap(), Answer::Int(0));
+    }}
+"
+    )
+}
+
+/// An example test reading `dayN.example.txt` and checking `answers` in part order.
+fn example_test(name: &str, day: u8, answers: &[Answer]) -> String {
+    let mut test = format!(
+        "    const EXAMPLE: &str = include_str!(\"inputs/day{day}.example.txt\");
+
+    #[test]
+    fn example() {{
+        let day = {name}::parse(EXAMPLE).unwrap();
+"
+    );
+    for (part, answer) in (1..).zip(answers) {
+        let expected = match answer {
+            Answer::Int(value) => format!("Answer::Int({})", value),
+            Answer::BigInt(value) => format!("Answer::BigInt({})", value),
+            Answer::Text(text) => format!("Answer::Text({:?}.to_string())", text),
+        };
+        test.push_str(&format!("        assert_eq!(day.part{}().unwrap(), {});\n", part, expected));
+    }
+    test.push_str("    }\n");
+    test
+}
+
+/// Replaces the example test of the module at `path` with one checking `answers` against
+/// `dayN.example.txt`.
+///
+/// Only a test still as [`new_day`] or an earlier call wrote it is replaced, so one edited by
+/// hand is kept. Returns whether the module changed.
+pub fn use_example(path: &Path, day: u8, answers: &[Answer]) -> io::Result<bool> {
+    let contents = fs::read_to_string(path)?;
+    let name = format!("Day{:02}", day);
+    let test = example_test(&name, day, answers);
+    let generated = std::iter::once(placeholder_test(&name))
+        .chain((1..answers.len()).map(|count| example_test(&name, day, &answers[..count])));
+    for old in generated {
+        if contents.contains(&old) {
+            fs::write(path, contents.replacen(&old, &test, 1))?;
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn year_module(year: u16) -> String {
+    format!(
+        "\
+use crate::solution::Entry;
+
+/// Every registered {year} solution, in the order they are listed and run.
+pub static REGISTRY: &[Entry] = &[
+];
+"
+    )
+}
+
+/// Adds `pub mod <module>;` and its registry entry to a year's `mod.rs`, keeping both sorted.
+///
+/// Returns `None` when the module is already declared or there is no `REGISTRY`.
+fn register_day(contents: &str, module: &str) -> Option<String> {
+    let declaration = format!("pub mod {};", module);
+    if contents.lines().any(|line| line.trim() == declaration) {
+        return None;
+    }
+    let name = format!("Day{}", &module[3..]);
+    let entry = format!("    Entry::of::<{}::{}>(),", module, name);
+
+    let mut lines: Vec<String> = contents.lines().map(str::to_string).collect();
+    let registry_start = lines.iter().position(|line| line.starts_with("pub static REGISTRY"))?;
+    let registry_end = registry_start + lines[registry_start..].iter().position(|line| line.trim() == "];")?;
+
+    let entry_at = (registry_start + 1..registry_end)
+        .find(|&i| entry_module(&lines[i]).is_some_and(|other| other > module))
+        .unwrap_or(registry_end);
+    lines.insert(entry_at, entry);
+
+    let declarations: Vec<usize> = (0..registry_start).filter(|&i| lines[i].starts_with("pub mod ")).collect();
+    match declarations.iter().find(|&&i| lines[i].as_str() > declaration.as_str()) {
+        Some(&i) => lines.insert(i, declaration),
+        None => match declarations.last() {
+            Some(&last) => lines.insert(last + 1, declaration),
+            None => {
+                lines.insert(0, String::new());
+                lines.insert(0, declaration);
+            }
+        },
+    }
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// The module named by a registry line such as `    Entry::of::<day06::Day06>(),`.
+fn entry_module(line: &str) -> Option<&str> {
+    line.trim().strip_prefix("Entry::of::<")?.split("::").next()
+}
+
+/// Declares `mod y<year>;` in `main.rs` among the other year modules, in year order.
+fn register_year_module(contents: &str, year: u16) -> Option<String> {
+    let mut lines: Vec<String> = contents.lines().map(str::to_string).collect();
+    let at = sorted_position(&lines, year, |line| line.strip_prefix("mod y")?.strip_suffix(';'))?;
+    lines.insert(at, format!("mod y{};", year));
+    Some(lines.join("\n") + "\n")
+}
+
+/// Imports `y<year>` in `years.rs` and adds it to `YEARS`, both in year order since
+/// `years::latest` takes the last year listed.
+fn register_year(contents: &str, year: u16) -> Option<String> {
+    let mut lines: Vec<String> = contents.lines().map(str::to_string).collect();
+    let at = sorted_position(&lines, year, |line| line.strip_prefix("use crate::y")?.strip_suffix(';'))?;
+    lines.insert(at, format!("use crate::y{};", year));
+
+    let years_start = lines.iter().position(|line| line.starts_with("pub static YEARS"))?;
+    let years_end = years_start + lines[years_start..].iter().position(|line| line.trim() == "];")?;
+    let at = (years_start + 1..years_end)
+        .find(|&i| listed_year(&lines[i]).is_some_and(|other| other > year))
+        .unwrap_or(years_end);
+    lines.insert(at, format!("    Year {{ year: {year}, registry: y{year}::REGISTRY }},"));
+    Some(lines.join("\n") + "\n")
+}
+
+/// Where a line for `year` goes among the lines naming a year through `year_in`: before the
+/// first later year, else after the last one. `None` when no line names a year.
+fn sorted_position(lines: &[String], year: u16, year_in: impl Fn(&str) -> Option<&str>) -> Option<usize> {
+    let years: Vec<(usize, u16)> =
+        (0..lines.len()).filter_map(|i| Some((i, year_in(&lines[i])?.parse().ok()?))).collect();
+    let &(last, _) = years.last()?;
+    Some(years.iter().find(|&&(_, other)| other > year).map_or(last + 1, |&(i, _)| i))
+}
+
+/// The year of a `YEARS` line such as `    Year { year: 2024, registry: y2024::REGISTRY },`.
+fn listed_year(line: &str) -> Option<u16> {
+    line.trim().strip_prefix("Year { year: ")?.split(',').next()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    const REGISTRY: &str = "\
+pub mod day01;
+pub mod day03;
+
+use crate::solution::Entry;
+
+/// Every registered 2024 solution, in the order they are listed and run.
+pub static REGISTRY: &[Entry] = &[
+    Entry::of::<day01::Day01>(),
+    Entry::of::<day03::Day03>(),
+];
+";
+
+    #[test]
+    fn registers_days_in_order() {
+        let contents = register_day(REGISTRY, "day02").unwrap();
+        let contents = register_day(&contents, "day04").unwrap();
+        assert_eq!(
+            contents,
+            "\
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+
+use crate::solution::Entry;
+
+/// Every registered 2024 solution, in the order they are listed and run.
+pub static REGISTRY: &[Entry] = &[
+    Entry::of::<day01::Day01>(),
+    Entry::of::<day02::Day02>(),
+    Entry::of::<day03::Day03>(),
+    Entry::of::<day04::Day04>(),
+];
+"
+        );
+    }
+
+    #[test]
+    fn refuses_registered_days() {
+        assert_eq!(register_day(REGISTRY, "day03"), None);
+    }
+
+    #[test]
+    fn registers_a_first_day_in_a_new_year() {
+        let contents = register_day(&year_module(2025), "day01").unwrap();
+        assert!(contents.starts_with("pub mod day01;\n\nuse crate::solution::Entry;"));
+        assert!(contents.contains("&[\n    Entry::of::<day01::Day01>(),\n];"));
+    }
+
+    #[test]
+    fn registers_a_new_year() {
+        let years = "\
+use crate::solution::Entry;
+use crate::y2024;
+
+pub static YEARS: &[Year] = &[
+    Year { year: 2024, registry: y2024::REGISTRY },
+];
+";
+        let contents = register_year(years, 2025).unwrap();
+        assert!(contents.contains("use crate::y2024;\nuse crate::y2025;\n"));
+        assert!(contents.contains("y2024::REGISTRY },\n    Year { year: 2025, registry: y2025::REGISTRY },\n];"));
+
+        let main = "mod solution;\nmod y2024;\nmod years;\n";
+        assert_eq!(register_year_module(main, 2025).unwrap(), "mod solution;\nmod y2024;\nmod y2025;\nmod years;\n");
+    }
+
+    #[test]
+    fn registers_an_older_year_before_the_newer_ones() {
+        let years = "\
+use crate::solution::Entry;
+use crate::y2024;
+
+pub static YEARS: &[Year] = &[
+    Year { year: 2024, registry: y2024::REGISTRY },
+];
+";
+        let contents = register_year(years, 2023).unwrap();
+        assert!(contents.contains("use crate::solution::Entry;\nuse crate::y2023;\nuse crate::y2024;\n"));
+        assert!(contents.contains("&[\n    Year { year: 2023, registry: y2023::REGISTRY },\n    Year { year: 2024,"));
+
+        let main = "mod solution;\nmod y2024;\nmod years;\n";
+        assert_eq!(register_year_module(main, 2023).unwrap(), "mod solution;\nmod y2023;\nmod y2024;\nmod years;\n");
+    }
+
+    #[test]
+    fn fills_in_the_example_test() {
+        let dir = TempDir::new("scaffold");
+        let path = dir.join("day07.rs");
+        fs::write(&path, day_module(2024, 7, "Bridge Repair")).unwrap();
+
+        assert!(use_example(&path, 7, &[Answer::Int(3749)]).unwrap());
+        let answers = [Answer::Int(3749), Answer::Text("4,6,3".to_string())];
+        assert!(use_example(&path, 7, &answers).unwrap());
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(contents.contains(
+            "    const EXAMPLE: &str = include_str!(\"inputs/day7.example.txt\");\n\n    #[test]\n    fn example() {\n"
+        ));
+        assert!(contents.contains("assert_eq!(day.part1().unwrap(), Answer::Int(3749));\n"));
+        assert!(contents.contains("assert_eq!(day.part2().unwrap(), Answer::Text(\"4,6,3\".to_string()));\n    }\n}\n"));
+
+        // A test edited by hand is left alone
+        fs::write(&path, contents.replace("3749", "3750")).unwrap();
+        assert!(!use_example(&path, 7, &answers).unwrap());
+    }
+
+    #[test]
+    fn a_failed_write_changes_no_file() {
+        let dir = TempDir::new("scaffold-commit");
+        let kept = dir.join("mod.rs");
+        fs::write(&kept, "old").unwrap();
+
+        let writes = vec![(kept.clone(), "new".to_string()), (dir.join("missing/day01.rs"), String::new())];
+        assert!(commit(writes).is_err());
+        assert_eq!(fs::read_to_string(&kept).unwrap(), "old");
+        assert_eq!(fs::read_dir(&*dir).unwrap().count(), 1);
+
+        assert_eq!(commit(vec![(kept.clone(), "new".to_string())]).unwrap(), vec![kept.clone()]);
+        assert_eq!(fs::read_to_string(&kept).unwrap(), "new");
+    }
+}
@@ -0,0 +1,235 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::config;
+use crate::http::{self, Response};
+use crate::input;
+
+/// Environment variable holding the adventofcode.com `session` cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable pointing the client at a stand-in for adventofcode.com.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Talks to adventofcode.com, or a stand-in at another base URL, as a logged-in user.
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client { base_url: base_url.trim_end_matches('/').to_string(), session: session.trim().to_string() }
+    }
+
+    /// A client set up from `$AOC_SESSION` and `$AOC_BASE_URL`, else from `aoc.toml`.
+    pub fn from_env() -> Result<Self, String> {
+        let config = config::get();
+        let session = env::var(SESSION_VAR).ok().or_else(|| config.session.clone()).ok_or_else(|| {
+            format!("no session token; set ${} or `session` in {}", SESSION_VAR, config::CONFIG_FILE)
+        })?;
+        let base_url = env::var(BASE_URL_VAR)
+            .ok()
+            .or_else(|| config.base_url.clone())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        Ok(Client::new(&base_url, &session))
+    }
+
+    /// The puzzle input of `day` of `year`.
+    pub fn input(&self, year: u16, day: u8) -> io::Result<String> {
+        let path = format!("/{}/day/{}/input", year, day);
+        let response = self.get(&path)?;
+        match response.status {
+            200..=299 => Ok(response.body),
+            404 => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("the input for {} day {} is not available yet", year, day),
+            )),
+            // The site answers a missing or expired session with 400 or 500
+            400 | 401 | 403 | 500 => Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("the server refused the session token (HTTP {}); log in again and update it", response.status),
+            )),
+            status => Err(io::Error::other(format!("HTTP {} from {}{}", status, self.base_url, path))),
+        }
+    }
+
+    /// The description page of `day` of `year`; part two is only on it once part one is solved.
+    pub fn puzzle(&self, year: u16, day: u8) -> io::Result<String> {
+        let path = format!("/{}/day/{}", year, day);
+        let response = self.get(&path)?;
+        match response.status {
+            200..=299 => Ok(response.body),
+            404 => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("the puzzle for {} day {} is not available yet", year, day),
+            )),
+            status => Err(io::Error::other(format!("HTTP {} from {}{}", status, self.base_url, path))),
+        }
+    }
+
+    /// Submits `answer` for `part` of `day` and returns the page the site replies with.
+    pub fn answer(&self, year: u16, day: u8, part: u8, answer: &str) -> io::Result<String> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let response = http::post_form(
+            &url,
+            &[("Cookie", &format!("session={}", self.session))],
+            &[("level", &part.to_string()), ("answer", answer)],
+        )?;
+        match response.status {
+            200..=299 => Ok(response.body),
+            400 | 401 | 403 | 500 => Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("the server refused the session token (HTTP {}); log in again and update it", response.status),
+            )),
+            status => Err(io::Error::other(format!("HTTP {} from {}", status, url))),
+        }
+    }
+
+    fn get(&self, path: &str) -> io::Result<Response> {
+        let url = format!("{}{}", self.base_url, path);
+        http::get(&url, &[("Cookie", &format!("session={}", self.session))])
+    }
+}
+
+/// Whether an input was downloaded or already on disk.
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    Cached(PathBuf),
+}
+
+/// Saves the input of `day` of `year` as `dayN.txt` in `dir`, unless a non-empty copy is
+/// already there under any of the names inputs are looked up by.
+pub fn fetch(client: &Client, year: u16, day: u8, dir: &Path) -> io::Result<Fetched> {
+    if let Some(path) = cached(dir, day) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let text = client.input(year, day)?;
+    fs::create_dir_all(dir)?;
+    let path = dir.join(format!("day{}.txt", day));
+    fs::write(&path, text)?;
+    Ok(Fetched::Downloaded(path))
+}
+
+fn cached(dir: &Path, day: u8) -> Option<PathBuf> {
+    input::file_names(day)
+        .into_iter()
+        .flat_map(|name| [dir.join(&name), dir.join(format!("{}.gz", name))])
+        .find(|path| fs::metadata(path).is_ok_and(|meta| meta.is_file() && meta.len() > 0))
+}
+
+/// A local stand-in for adventofcode.com, so tests need no network.
+#[cfg(test)]
+pub mod stand_in {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// A request as the stand-in saw it.
+    #[derive(Debug, PartialEq)]
+    pub struct Request {
+        pub method: String,
+        pub path: String,
+        pub session: Option<String>,
+        pub body: String,
+    }
+
+    /// Answers `count` requests with `respond`'s status line and body, then returns what it saw.
+    pub fn serve(
+        count: usize,
+        respond: impl Fn(&Request) -> (&'static str, String) + Send + 'static,
+    ) -> (String, thread::JoinHandle<Vec<Request>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let mut seen = Vec::new();
+            for stream in listener.incoming().take(count) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut words = request_line.split_whitespace();
+                let (method, path) = (words.next().unwrap().to_string(), words.next().unwrap().to_string());
+
+                let mut session = None;
+                let mut length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let Some((name, value)) = header.trim().split_once(": ") else { break };
+                    match name {
+                        "Cookie" => session = value.strip_prefix("session=").map(str::to_string),
+                        "Content-Length" => length = value.parse().unwrap(),
+                        _ => {}
+                    }
+                }
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+
+                let request = Request { method, path, session, body: String::from_utf8(body).unwrap() };
+                let (status, body) = respond(&request);
+                write!(stream, "HTTP/1.1 {}\r\nContent-Length: {}\r\n\r\n{}", status, body.len(), body).unwrap();
+                seen.push(request);
+            }
+            seen
+        });
+        (base_url, server)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    /// Serves inputs like adventofcode.com would, accepting only `session=secret`.
+    fn stand_in(count: usize) -> (String, std::thread::JoinHandle<Vec<stand_in::Request>>) {
+        stand_in::serve(count, |request| match (request.session.as_deref(), request.path.as_str()) {
+            (Some("secret"), "/2024/day/1/input") => ("200 OK", "3   4\n4   3\n".to_string()),
+            (_, "/2024/day/1") => ("200 OK", "<article class=\"day-desc\">--- Day 1 ---</article>".to_string()),
+            (Some("secret"), _) => ("404 Not Found", "Please don't repeatedly request this endpoint before it unlocks!\n".to_string()),
+            _ => ("400 Bad Request", "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n".to_string()),
+        })
+    }
+
+    #[test]
+    fn downloads_once_then_uses_the_cache() {
+        let dir = TempDir::new("fetch");
+        let (base_url, server) = stand_in(1);
+        let client = Client::new(&base_url, "secret\n");
+
+        let path = dir.join("day1.txt");
+        assert_eq!(fetch(&client, 2024, 1, &dir).unwrap(), Fetched::Downloaded(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n4   3\n");
+        assert_eq!(fetch(&client, 2024, 1, &dir).unwrap(), Fetched::Cached(path));
+
+        let seen = server.join().unwrap();
+        assert_eq!(seen.len(), 1);
+        assert_eq!((seen[0].method.as_str(), seen[0].path.as_str()), ("GET", "/2024/day/1/input"));
+    }
+
+    #[test]
+    fn reads_the_puzzle_page() {
+        let (base_url, server) = stand_in(2);
+        let client = Client::new(&base_url, "secret");
+        assert!(client.puzzle(2024, 1).unwrap().contains("--- Day 1 ---"));
+        assert_eq!(client.puzzle(2024, 25).unwrap_err().kind(), io::ErrorKind::NotFound);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn explains_refusals() {
+        let (base_url, server) = stand_in(2);
+        let err = Client::new(&base_url, "stale").input(2024, 1).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+        let err = Client::new(&base_url, "secret").input(2024, 25).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        server.join().unwrap();
+    }
+}
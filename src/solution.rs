@@ -0,0 +1,81 @@
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::params::Param;
+
+/// Static description of a puzzle solution.
+#[derive(Debug, Clone, Copy)]
+pub struct Meta {
+    /// The Advent of Code event, e.g. `2024`.
+    pub year: u16,
+    pub day: u8,
+    /// Distinguishes alternative solutions for the same day, e.g. `Some("p2")` for `14p2`.
+    pub variant: Option<&'static str>,
+    pub title: &'static str,
+}
+
+impl Meta {
+    /// The key used to select this solution within its year, e.g. `"6"` or `"14p2"`.
+    pub fn key(&self) -> String {
+        match self.variant {
+            Some(variant) => format!("{}{}", self.day, variant),
+            None => self.day.to_string(),
+        }
+    }
+}
+
+/// A day's puzzle: how to parse the input and answer each part.
+///
+/// Parts that are not solved yet keep the default implementation and return
+/// `Error::Unimplemented`.
+pub trait Solution: Sized {
+    const META: Meta;
+    /// Puzzle constants that can be overridden on the command line.
+    const PARAMS: &'static [Param] = &[];
+
+    fn parse(input: &str) -> Result<Self>;
+
+    fn part1(&self) -> Result<Answer> {
+        Err(Error::Unimplemented)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Err(Error::Unimplemented)
+    }
+}
+
+/// Object-safe view of a parsed `Solution`, so every day can live in one registry.
+pub trait Puzzle {
+    fn part1(&self) -> Result<Answer>;
+    fn part2(&self) -> Result<Answer>;
+}
+
+impl<S: Solution> Puzzle for S {
+    fn part1(&self) -> Result<Answer> {
+        Solution::part1(self)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Solution::part2(self)
+    }
+}
+
+/// A registered solution: its metadata plus a constructor for its parsed form.
+pub struct Entry {
+    pub meta: Meta,
+    pub params: &'static [Param],
+    pub parse: fn(&str) -> Result<Box<dyn Puzzle>>,
+}
+
+impl Entry {
+    pub const fn of<S: Solution + 'static>() -> Self {
+        Entry {
+            meta: S::META,
+            params: S::PARAMS,
+            parse: parse_boxed::<S>,
+        }
+    }
+}
+
+fn parse_boxed<S: Solution + 'static>(input: &str) -> Result<Box<dyn Puzzle>> {
+    Ok(Box::new(S::parse(input).map_err(|err| err.locate(input))?))
+}
@@ -0,0 +1,106 @@
+use std::collections::BTreeMap;
+use std::io;
+
+use crate::answers::{AnswerBook, ANSWERS_FILE};
+use crate::input;
+use crate::log;
+use crate::solution::Meta;
+use crate::years;
+
+mod sources {
+    include!(concat!(env!("OUT_DIR"), "/sources.rs"));
+}
+
+/// What the build script found in a solution's module.
+#[derive(Debug, Default, PartialEq)]
+struct Source {
+    parts: [bool; 2],
+    /// Tests of the puzzle's examples; other tests are not counted.
+    examples: usize,
+    ignored: usize,
+}
+
+fn source(meta: &Meta) -> Source {
+    let key = meta.key();
+    sources::SOURCES
+        .iter()
+        .find(|(year, source_key, ..)| *year == meta.year && *source_key == key)
+        .map(|&(_, _, part1, part2, examples, ignored)| Source { parts: [part1, part2], examples, ignored })
+        .unwrap_or_default()
+}
+
+/// Whether the day's input can be found and has any content.
+fn input_state(meta: &Meta) -> &'static str {
+    match input::load(meta.year, meta.day, None) {
+        Ok(input) if input.text.trim().is_empty() => "empty",
+        Ok(_) => "yes",
+        Err(err) if err.kind() == io::ErrorKind::NotFound => "missing",
+        Err(_) => "unreadable",
+    }
+}
+
+/// Prints which parts of each registered day of `year`, or of every year, are implemented, how
+/// many example tests it has, whether its input is present, and the answers recorded as verified.
+pub fn status(year: Option<u16>) {
+    let answers = AnswerBook::load(ANSWERS_FILE)
+        .map_err(|err| log::warning!("not showing answers: {}", err))
+        .ok();
+
+    println!(
+        "{:>4} {:>5}  {:<5}  {:<13}  {:<10}  {:<20}  part 2 answer",
+        "year", "day", "parts", "examples", "input", "part 1 answer"
+    );
+    // Variants such as 14p2 share their day's two parts
+    let mut days: BTreeMap<(u16, u8), [bool; 2]> = BTreeMap::new();
+    for entry in years::entries(year) {
+        let meta = &entry.meta;
+        let source = source(meta);
+        let parts: Vec<String> = (1..=2u8)
+            .map(|part| if source.parts[part as usize - 1] { part.to_string() } else { "-".to_string() })
+            .collect();
+        let examples = match (source.examples, source.ignored) {
+            (0, _) => "none".to_string(),
+            (examples, 0) => examples.to_string(),
+            (examples, ignored) => format!("{} ({} ignored)", examples, ignored),
+        };
+        let answer = |part| {
+            let answer = answers.as_ref().and_then(|answers| answers.get(meta.year, &meta.key(), part));
+            answer.map_or("-".to_string(), ToString::to_string)
+        };
+
+        let day = days.entry((meta.year, meta.day)).or_default();
+        day[0] |= source.parts[0];
+        day[1] |= source.parts[1];
+        println!(
+            "{:>4} {:>5}  {:<5}  {:<13}  {:<10}  {:<20}  {}",
+            meta.year,
+            meta.key(),
+            parts.join(" "),
+            examples,
+            input_state(meta),
+            answer(1),
+            answer(2)
+        );
+    }
+    let implemented = days.values().flatten().filter(|&&part| part).count();
+    println!("\n{} of {} parts implemented", implemented, days.len() * 2);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Solution;
+    use crate::y2024::{day13, day14p2, day16, day17, day21};
+
+    #[test]
+    fn reads_implemented_parts_and_example_tests_from_the_sources() {
+        assert_eq!(source(&day13::Day13::META).parts, [false, true]);
+        assert_eq!(source(&day14p2::Day14p2::META).parts, [false, true]);
+        assert_eq!(source(&day17::Day17::META).parts, [false, false]);
+
+        let day16 = source(&day16::Day16::META);
+        assert_eq!((day16.parts, day16.examples, day16.ignored), ([true, true], 1, 0));
+        let day21 = source(&day21::Day21::META);
+        assert_eq!((day21.parts, day21.examples, day21.ignored), ([true, false], 1, 1));
+    }
+}
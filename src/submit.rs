@@ -0,0 +1,372 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::answer::Answer;
+use crate::answers::{AnswerBook, ANSWERS_FILE};
+use crate::html;
+use crate::runner::{self, Checking, Status};
+use crate::site::Client;
+use crate::solution::Entry;
+
+/// Every answer sent and what the site said about it, at the crate root.
+pub const SUBMISSIONS_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/submissions.log");
+
+/// How long the site makes you wait after a wrong answer when its reply does not say.
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint.
+    Wrong,
+    /// Not judged, because the previous answer was too recent.
+    TooSoon,
+    /// Not judged, because the part is already solved or not unlocked.
+    WrongLevel,
+}
+
+impl Verdict {
+    const ALL: [Verdict; 6] =
+        [Verdict::Correct, Verdict::TooHigh, Verdict::TooLow, Verdict::Wrong, Verdict::TooSoon, Verdict::WrongLevel];
+
+    fn label(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+            Verdict::TooSoon => "too-soon",
+            Verdict::WrongLevel => "wrong-level",
+        }
+    }
+
+    fn rejected(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+/// The site's reply to an answer.
+#[derive(Debug, PartialEq)]
+pub struct Reply {
+    pub verdict: Verdict,
+    /// How long to wait before sending another answer.
+    pub wait: Duration,
+    /// The reply's text, without markup.
+    pub message: String,
+}
+
+/// Reads the verdict and wait time out of the page the site returns for an answer.
+pub fn parse_reply(html: &str) -> Option<Reply> {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+    let message = strip_tags(article);
+
+    let verdict = if message.contains("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("That's not the right answer") {
+        if message.contains("too high") {
+            Verdict::TooHigh
+        } else if message.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if message.contains("You gave an answer too recently") {
+        Verdict::TooSoon
+    } else if message.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        return None;
+    };
+
+    let wait = match verdict {
+        Verdict::TooSoon => left_to_wait(&message).unwrap_or(DEFAULT_COOLDOWN),
+        verdict if verdict.rejected() => cooldown(&message).unwrap_or(DEFAULT_COOLDOWN),
+        _ => Duration::ZERO,
+    };
+    Some(Reply { verdict, wait, message })
+}
+
+fn strip_tags(html: &str) -> String {
+    html::text(html).split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads "You have 1m 23s left to wait".
+fn left_to_wait(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (amount, _) = rest.split_once(" left to wait")?;
+    let mut seconds = 0;
+    for part in amount.split_whitespace() {
+        let unit = part.chars().last()?;
+        let value: u64 = part[..part.len() - 1].parse().ok()?;
+        seconds += value * match unit {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// Reads "please wait one minute" or "please wait 5 minutes".
+fn cooldown(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("wait ")?;
+    let mut words = rest.split_whitespace();
+    let minutes = match words.next()? {
+        "one" => 1,
+        number => number.parse().ok()?,
+    };
+    words.next()?.starts_with("minute").then_some(Duration::from_secs(minutes * 60))
+}
+
+/// One line of the submission log: `<unix time> <year> <day> <part> <verdict> <wait secs> <answer>`.
+#[derive(Debug, Clone, PartialEq)]
+struct Submission {
+    at: u64,
+    year: u16,
+    day: u8,
+    part: u8,
+    verdict: Verdict,
+    wait: u64,
+    answer: String,
+}
+
+impl Submission {
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.splitn(7, ' ');
+        let mut next = || fields.next();
+        Some(Submission {
+            at: next()?.parse().ok()?,
+            year: next()?.parse().ok()?,
+            day: next()?.parse().ok()?,
+            part: next()?.parse().ok()?,
+            verdict: {
+                let label = next()?;
+                Verdict::ALL.into_iter().find(|verdict| verdict.label() == label)?
+            },
+            wait: next()?.parse().ok()?,
+            answer: next()?.to_string(),
+        })
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "{} {} {} {} {} {} {}",
+            self.at,
+            self.year,
+            self.day,
+            self.part,
+            self.verdict.label(),
+            self.wait,
+            self.answer
+        )
+    }
+}
+
+/// The answers already sent, so that none is sent twice and the site's wait times are kept.
+pub struct SubmissionLog {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    /// Loads the log at `path`; a missing file is an empty log.
+    pub fn load(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+
+        let mut submissions = Vec::new();
+        for (index, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let submission = Submission::parse(line.trim()).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("{}: line {}: malformed", path.display(), index + 1))
+            })?;
+            submissions.push(submission);
+        }
+        Ok(SubmissionLog { path, submissions })
+    }
+
+    fn record(&mut self, submission: Submission) -> io::Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        if file.metadata()?.len() == 0 {
+            writeln!(file, "# unix-time year day part verdict wait-secs answer")?;
+        }
+        writeln!(file, "{}", submission.to_line())?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+
+    /// Why `answer` need not be sent, when earlier replies already settle it.
+    fn settled(&self, year: u16, day: u8, part: u8, answer: &str) -> Option<String> {
+        let earlier = self.submissions.iter().filter(|sent| (sent.year, sent.day, sent.part) == (year, day, part));
+        let number = answer.parse::<i128>().ok();
+
+        for sent in earlier {
+            let sent_number = sent.answer.parse::<i128>().ok();
+            match sent.verdict {
+                Verdict::Correct if sent.answer == answer => return Some(format!("{} was already accepted", answer)),
+                Verdict::Correct => return Some(format!("{} was already accepted instead", sent.answer)),
+                verdict if verdict.rejected() && sent.answer == answer => {
+                    return Some(format!("{} was already rejected ({})", answer, verdict.label()))
+                }
+                Verdict::TooHigh if number.zip(sent_number).is_some_and(|(number, high)| number >= high) => {
+                    return Some(format!("{} was too high, so {} is too", sent.answer, answer))
+                }
+                Verdict::TooLow if number.zip(sent_number).is_some_and(|(number, low)| number <= low) => {
+                    return Some(format!("{} was too low, so {} is too", sent.answer, answer))
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// How much longer the site wants us to wait before the next answer.
+    fn wait_left(&self, now: u64) -> Option<Duration> {
+        let until = self.submissions.iter().map(|sent| sent.at + sent.wait).max()?;
+        (until > now).then(|| Duration::from_secs(until - now))
+    }
+}
+
+/// Sends `answer` unless the log settles it or the site's wait time has not passed yet;
+/// either of those is an `Err` explaining why nothing was sent.
+pub fn submit(
+    client: &Client,
+    log: &mut SubmissionLog,
+    (year, day, part): (u16, u8, u8),
+    answer: &Answer,
+    now: SystemTime,
+) -> Result<Reply, String> {
+    let answer = answer.to_string();
+    if let Some(reason) = log.settled(year, day, part, &answer) {
+        return Err(format!("not submitting: {}", reason));
+    }
+    let now = now.duration_since(UNIX_EPOCH).map_or(0, |since| since.as_secs());
+    if let Some(left) = log.wait_left(now) {
+        return Err(format!("not submitting: the site asked to wait another {}s", left.as_secs()));
+    }
+
+    let page = client.answer(year, day, part, &answer).map_err(|err| err.to_string())?;
+    let reply = parse_reply(&page).ok_or_else(|| format!("could not understand the reply:\n{}", strip_tags(&page)))?;
+    let submission = Submission { at: now, year, day, part, verdict: reply.verdict, wait: reply.wait.as_secs(), answer };
+    log.record(submission).map_err(|err| format!("could not record the reply in {}: {}", log.path.display(), err))?;
+    Ok(reply)
+}
+
+/// Solves `part` of `entry` on its own input and submits the answer, recording it in the answer
+/// book when correct, in place of any answer a run recorded before.
+pub fn submit_day(entry: &Entry, part: u8) -> Result<(), String> {
+    let meta = &entry.meta;
+    let mut outcomes = runner::run_parts(entry, &[part], None, None);
+    let outcome = outcomes.remove(0);
+    let answer = match (&outcome.status, &outcome.answer) {
+        (Status::Ok, Some(answer)) => answer.clone(),
+        _ => {
+            runner::report(entry, vec![outcome], Checking::Off);
+            return Err("no answer to submit".to_string());
+        }
+    };
+    println!("{} day {} part {}: {}", meta.year, meta.key(), part, answer);
+
+    // The answer book also holds whatever `run` printed as NEW, so only the log says what the
+    // site has accepted
+    let client = Client::from_env()?;
+    let mut log = SubmissionLog::load(SUBMISSIONS_FILE).map_err(|err| err.to_string())?;
+    let reply = submit(&client, &mut log, (meta.year, meta.day, part), &answer, SystemTime::now())?;
+    println!("{}", reply.message);
+    match reply.verdict {
+        Verdict::Correct => {
+            let mut book = AnswerBook::load(ANSWERS_FILE).map_err(|err| err.to_string())?;
+            book.record(meta.year, &meta.key(), part, &answer);
+            book.save().map_err(|err| format!("could not save {}: {}", book.path().display(), err))?;
+            println!("recorded in {}", book.path().display());
+        }
+        verdict if verdict.rejected() || verdict == Verdict::TooSoon => {
+            println!("{}; wait {}s before the next answer", verdict.label(), reply.wait.as_secs());
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::site::stand_in;
+    use crate::testing::TempDir;
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>", article)
+    }
+
+    #[test]
+    fn reads_the_replies() {
+        let reply = parse_reply(&page(
+            "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the \
+             full input data; please wait one minute before trying again. <a href=\"/2024/day/1\">[Return to Day 1]</a>",
+        ))
+        .unwrap();
+        assert_eq!((reply.verdict, reply.wait), (Verdict::TooHigh, Duration::from_secs(60)));
+        assert!(reply.message.ends_with("[Return to Day 1]"));
+
+        let reply = parse_reply(&page(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again.  \
+             You have 1m 23s left to wait.",
+        ))
+        .unwrap();
+        assert_eq!((reply.verdict, reply.wait), (Verdict::TooSoon, Duration::from_secs(83)));
+
+        let reply = parse_reply(&page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."));
+        assert_eq!(reply.unwrap().verdict, Verdict::Correct);
+        assert!(parse_reply("<html>Service unavailable</html>").is_none());
+    }
+
+    #[test]
+    fn never_resends_a_settled_answer_and_keeps_the_wait() {
+        let dir = TempDir::new("submit");
+        let path = dir.join("submissions.log");
+        let (base_url, server) = stand_in::serve(2, |request| {
+            let article = if request.body.ends_with("answer=50") {
+                "That's the right answer!"
+            } else {
+                "That's not the right answer; your answer is too high.  Please wait one minute before trying again."
+            };
+            ("200 OK", page(article))
+        });
+        let client = Client::new(&base_url, "secret");
+        let mut log = SubmissionLog::load(&path).unwrap();
+        let start = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let day = (2024, 1, 2);
+
+        let reply = submit(&client, &mut log, day, &Answer::Int(100), start).unwrap();
+        assert_eq!(reply.verdict, Verdict::TooHigh);
+        let err = submit(&client, &mut log, day, &Answer::Int(150), start).unwrap_err();
+        assert!(err.contains("100 was too high"), "{}", err);
+        let err = submit(&client, &mut log, day, &Answer::Int(50), start + Duration::from_secs(30)).unwrap_err();
+        assert!(err.contains("wait another 30s"), "{}", err);
+
+        let later = start + Duration::from_secs(61);
+        assert_eq!(submit(&client, &mut log, day, &Answer::Int(50), later).unwrap().verdict, Verdict::Correct);
+        let seen = server.join().unwrap();
+        assert_eq!(seen[0].path, "/2024/day/1/answer");
+        assert_eq!(seen[0].body, "level=2&answer=100");
+
+        // The log survives a reload
+        let log = SubmissionLog::load(&path).unwrap();
+        assert_eq!(log.settled(2024, 1, 2, "50"), Some("50 was already accepted".to_string()));
+    }
+}
@@ -0,0 +1,160 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::input::{self, Origin};
+use crate::log;
+use crate::solution::Entry;
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The crate root, where `cargo` is run from.
+const MANIFEST_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+/// Modification times of every watched file, to compare between polls.
+type Snapshot = Vec<(PathBuf, Option<SystemTime>)>;
+
+/// Re-runs the day's tests and solution through `cargo` whenever its sources or input change.
+///
+/// The source tree (`src`, `Cargo.toml` and `build.rs`) and the day's input file are polled for
+/// modification times, so no file-watcher dependency is needed. Runs until interrupted.
+pub fn watch(entry: &Entry, part: Option<u8>, input: Option<&Path>) -> io::Result<()> {
+    if input == Some(Path::new("-")) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "cannot watch stdin"));
+    }
+
+    let mut last = snapshot(&watched(entry, input));
+    run_once(entry, part, input);
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let current = snapshot(&watched(entry, input));
+        if current == last {
+            continue;
+        }
+
+        // Editors often write a file in several steps, so wait for things to settle
+        let mut settled = current;
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let next = snapshot(&watched(entry, input));
+            if next == settled {
+                break;
+            }
+            settled = next;
+        }
+        last = settled;
+        run_once(entry, part, input);
+    }
+}
+
+/// The paths to poll; the input is resolved again each time as it may have been created or moved.
+fn watched(entry: &Entry, input: Option<&Path>) -> Vec<PathBuf> {
+    let root = Path::new(MANIFEST_DIR);
+    let mut paths = vec![root.join("src"), root.join("Cargo.toml"), root.join("build.rs")];
+    match input {
+        Some(path) => paths.push(path.to_path_buf()),
+        None => {
+            if let Ok(Origin::File(path) | Origin::Archive { archive: path, .. }) =
+                input::resolve(entry.meta.year, entry.meta.day)
+            {
+                paths.push(path);
+            }
+        }
+    }
+    paths
+}
+
+/// Records the modification time of every file under `paths`, recursing into directories.
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut files = Vec::new();
+    for path in paths {
+        collect(path, &mut files);
+    }
+    files.sort();
+    files.dedup();
+    files
+}
+
+fn collect(path: &Path, files: &mut Snapshot) {
+    match fs::read_dir(path) {
+        Ok(entries) => {
+            for entry in entries.flatten() {
+                collect(&entry.path(), files);
+            }
+        }
+        // Missing files are recorded too, so that creating one counts as a change
+        Err(_) => files.push((path.to_path_buf(), fs::metadata(path).and_then(|meta| meta.modified()).ok())),
+    }
+}
+
+fn run_once(entry: &Entry, part: Option<u8>, input: Option<&Path>) {
+    let meta = &entry.meta;
+    log::info!("{} day {} - {}", meta.year, meta.key(), meta.title);
+
+    // Only this day's module tests, e.g. `y2024::day06::`
+    let filter = format!("y{}::day{:02}{}::", meta.year, meta.day, meta.variant.unwrap_or(""));
+    let mut test = cargo("test");
+    test.args(["--", &filter]);
+    if !succeeded(test) {
+        log::info!("tests failed, waiting for changes");
+        return;
+    }
+
+    let mut run = cargo("run");
+    run.args(["--", "run", "--year", &meta.year.to_string(), "--day", &meta.key()]);
+    if let Some(part) = part {
+        run.args(["--part", &part.to_string()]);
+    }
+    if let Some(input) = input {
+        run.arg("--input").arg(input);
+    }
+    succeeded(run);
+    log::info!("waiting for changes");
+}
+
+/// A `cargo` invocation in the crate root, in the same profile as this binary.
+fn cargo(subcommand: &str) -> Command {
+    let mut command = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.current_dir(MANIFEST_DIR).args([subcommand, "--quiet"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    command
+}
+
+fn succeeded(mut command: Command) -> bool {
+    match command.status() {
+        Ok(status) => status.success(),
+        Err(err) => {
+            log::error!("could not start cargo: {}", err);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    #[test]
+    fn snapshot_sees_new_and_changed_files() {
+        let dir = TempDir::new("watch");
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        let file = dir.join("nested").join("day01.txt");
+        let paths = [dir.to_path_buf()];
+
+        let empty = snapshot(&paths);
+        fs::write(&file, "1").unwrap();
+        let created = snapshot(&paths);
+        assert_ne!(empty, created);
+
+        let earlier = SystemTime::now() - Duration::from_secs(60);
+        fs::File::options().write(true).open(&file).unwrap().set_modified(earlier).unwrap();
+        assert_ne!(created, snapshot(&paths));
+    }
+}
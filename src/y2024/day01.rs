@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use crate::answer::Answer;
+use crate::error::{parse_num, Error, Result};
+use crate::solution::{Meta, Solution};
+
+pub struct Day01 {
+    left_list: Vec<i32>,
+    right_list: Vec<i32>,
+}
+
+impl Solution for Day01 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 1,
+        variant: None,
+        title: "Historian Hysteria",
+    };
+
+    fn parse(input: &str) -> Result<Self> {
+        let (left_list, right_list) = read_numbers(input)?;
+        Ok(Day01 { left_list, right_list })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(part1(&self.left_list, &self.right_list).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(part2(&self.left_list, &self.right_list).into())
+    }
+}
+
+fn part1(left_list: &[i32], right_list: &[i32]) -> i32 {
+    let mut left_sorted = left_list.to_vec(); // TODO: what is to_vec
+    let mut right_sorted = right_list.to_vec();
+
+    left_sorted.sort();
+    right_sorted.sort();
+
+    left_sorted
+        .iter()
+        .zip(right_sorted.iter()) // TODO: what is zip
+        .map(|(l,r)|(l-r).abs())
+        .sum()
+}
+
+fn part2(left_list: &[i32], right_list: &[i32]) -> i32 {
+    let mut right_counts = HashMap::new();
+    for &num in right_list {
+        *right_counts.entry(num).or_insert(0) += 1;
+    }
+
+    left_list
+        .iter()
+        .map(|&num| num * right_counts.get(&num).unwrap_or(&0))
+        .sum()
+}
+
+
+fn read_numbers(input: &str) -> Result<(Vec<i32>, Vec<i32>)> {
+    let mut left_list = Vec::new();
+    let mut right_list = Vec::new();
+
+    for line in input.lines() {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        match parts[..] {
+            [left, right] => {
+                left_list.push(parse_num(left)?);
+                right_list.push(parse_num(right)?);
+            }
+            [] => {}
+            _ => return Err(Error::unexpected(line, "two numbers per line")),
+        }
+    }
+
+    Ok((left_list, right_list))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn example() {
+        let day = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1().unwrap(), Answer::Int(11));
+        assert_eq!(day.part2().unwrap(), Answer::Int(31));
+    }
+
+    #[test]
+    fn rejects_lines_without_two_numbers() {
+        assert!(Day01::parse("3   4\n4\n").is_err());
+    }
+}
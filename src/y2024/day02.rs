@@ -1,33 +1,41 @@
-use std::fs;
+use crate::answer::Answer;
+use crate::error::{parse_num, Result};
+use crate::solution::{Meta, Solution};
 
-pub fn run() {
-    // Read the input file
-    let reports = read_reports("src/days/inputs/day2.txt");
+pub struct Day02 {
+    reports: Vec<Vec<i32>>,
+}
+
+impl Solution for Day02 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 2,
+        variant: None,
+        title: "Red-Nosed Reports",
+    };
 
-    print!("Day 2!!\n");
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Day02 { reports: read_reports(input)? })
+    }
 
     // Part 1: Count safe reports
-    let safe_count = reports.iter().filter(|report| is_safe(report)).count();
-    println!("Part 1: The number of safe reports is: {}", safe_count);
+    fn part1(&self) -> Result<Answer> {
+        let safe_count = self.reports.iter().filter(|report| is_safe(report)).count();
+        Ok(safe_count.into())
+    }
 
     // Part 2: Count safe reports with the Problem Dampener
-    let safe_with_dampener_count = reports.iter().filter(|report| is_safe_with_dampener(report)).count();
-    println!(
-        "Part 2: The number of safe reports with the Problem Dampener is: {}",
-        safe_with_dampener_count
-    );
+    fn part2(&self) -> Result<Answer> {
+        let safe_with_dampener_count = self.reports.iter().filter(|report| is_safe_with_dampener(report)).count();
+        Ok(safe_with_dampener_count.into())
+    }
 }
 
-// Read the input file and parse it into a list of reports
-fn read_reports(filename: &str) -> Vec<Vec<i32>> {
-    let input = fs::read_to_string(filename).expect("Failed to read file");
+// Parse the input into a list of reports
+fn read_reports(input: &str) -> Result<Vec<Vec<i32>>> {
     input
         .lines()
-        .map(|line| {
-            line.split_whitespace()
-                .map(|num| num.parse::<i32>().expect("Failed to parse number"))
-                .collect()
-        })
+        .map(|line| line.split_whitespace().map(parse_num).collect())
         .collect()
 }
 
@@ -78,3 +86,24 @@ fn is_safe_with_dampener(report: &[i32]) -> bool {
 
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    #[test]
+    fn example() {
+        let day = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1().unwrap(), Answer::Int(2));
+        assert_eq!(day.part2().unwrap(), Answer::Int(4));
+    }
+}
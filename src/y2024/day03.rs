@@ -1,14 +1,32 @@
 use regex::Regex;
 
-pub fn run() {
-    let input = std::fs::read_to_string("src/days/inputs/day3.txt").expect("Failed to read input");
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solution::{Meta, Solution};
 
-    // Part 1
-    let part1_result = part1(&input);
-    println!("Part 1: {}", part1_result);
+pub struct Day03 {
+    input: String,
+}
+
+impl Solution for Day03 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 3,
+        variant: None,
+        title: "Mull It Over",
+    };
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Day03 { input: input.to_string() })
+    }
 
-    // Part 2
-    part2();
+    fn part1(&self) -> Result<Answer> {
+        Ok(part1(&self.input).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(part2(&self.input).into())
+    }
 }
 
 fn part1(input: &str) -> i64 {
@@ -22,8 +40,6 @@ fn part1(input: &str) -> i64 {
         .sum()
 }
 
-use std::fs;
-
 /// Finds all positions of a word in the content
 fn get_positions_of_word(content: &str, word: &str) -> Vec<usize> {
     let mut positions = Vec::new();
@@ -84,15 +100,27 @@ fn get_total(content: &str) -> i64 {
         .sum()
 }
 
-pub fn part2() {
-    let file_path = "src/days/inputs/day3.txt";
-    let content = fs::read_to_string(file_path).expect("Failed to read input file");
-
+fn part2(content: &str) -> i64 {
     // Apply `do()` and `don't()` filtering logic
-    let enabled_instructions = get_enabled_instructions(&content);
+    let enabled_instructions = get_enabled_instructions(content);
 
     // Calculate the total from valid `mul` instructions
-    let total = get_total(&enabled_instructions);
+    get_total(&enabled_instructions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part 2 Total: {}", total);
+    #[test]
+    fn example_part1() {
+        let day = Day03::parse("xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))").unwrap();
+        assert_eq!(day.part1().unwrap(), Answer::Int(161));
+    }
+
+    #[test]
+    fn example_part2() {
+        let day = Day03::parse("xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))").unwrap();
+        assert_eq!(day.part2().unwrap(), Answer::Int(48));
+    }
 }
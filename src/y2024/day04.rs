@@ -1,30 +1,37 @@
-use std::fs;
-use std::io::{self, BufRead};
-
-pub fn run() {
-    let file_path = "src/days/inputs/day4.txt";
-    let grid = read_input(file_path).expect("Failed to read input");
-    let ans1 = part1(&grid);
-    let ans2 =part2(&grid);
-    print!("Day 4!!\n");
-    println!("part1: {}", ans1);
-    println!("part2: {}", ans2);
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solution::{Meta, Solution};
+
+pub struct Day04 {
+    grid: Vec<Vec<char>>,
 }
 
-fn read_input(file_path: &str) -> io::Result<Vec<Vec<char>>> {
-    let file = fs::File::open(file_path)?;
-    let reader = io::BufReader::new(file);
+impl Solution for Day04 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 4,
+        variant: None,
+        title: "Ceres Search",
+    };
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Day04 { grid: read_input(input) })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(part1(&self.grid).into())
+    }
 
-    let mut grid = Vec::new();
-    for line in reader.lines() {
-        let line = line?;
-        grid.push(line.chars().collect());
+    fn part2(&self) -> Result<Answer> {
+        Ok(part2(&self.grid).into())
     }
+}
 
-    Ok(grid)
+fn read_input(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
 }
 
-fn part1(grid: &Vec<Vec<char>>) -> usize {
+fn part1(grid: &[Vec<char>]) -> usize {
     let word = "XMAS";
     let directions = vec![
         (0, 1),   // Right
@@ -53,12 +60,11 @@ fn part1(grid: &Vec<Vec<char>>) -> usize {
     count
 }
 
-fn part2(grid: &Vec<Vec<char>>) -> usize {
-    let count = count_x_mas(&grid);
-    count
+fn part2(grid: &[Vec<char>]) -> usize {
+    count_x_mas(grid)
 }
 
-fn count_x_mas(grid: &Vec<Vec<char>>) -> usize {
+fn count_x_mas(grid: &[Vec<char>]) -> usize {
     let rows = grid.len() as isize;
     let mut count = 0;
     let sequences = vec![
@@ -108,7 +114,7 @@ fn count_x_mas(grid: &Vec<Vec<char>>) -> usize {
     count
 }
 
-fn in_bounds(grid: &Vec<Vec<char>>, pos: (isize, isize)) -> bool {
+fn in_bounds(grid: &[Vec<char>], pos: (isize, isize)) -> bool {
     let (x, y) = pos;
     if x < 0 || x >= grid.len() as isize {
         return false;
@@ -120,7 +126,7 @@ fn in_bounds(grid: &Vec<Vec<char>>, pos: (isize, isize)) -> bool {
 }
 
 fn check_word(
-    grid: &Vec<Vec<char>>,
+    grid: &[Vec<char>],
     word: &str,
     start_x: isize,
     start_y: isize,
@@ -147,3 +153,28 @@ fn check_word(
 
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    #[test]
+    fn example() {
+        let day = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1().unwrap(), Answer::Int(18));
+        assert_eq!(day.part2().unwrap(), Answer::Int(9));
+    }
+}
@@ -1,55 +1,71 @@
-use std::fs;
-use std::io::{self, BufRead};
 use std::collections::{HashMap, HashSet, VecDeque};
 
-pub fn run() {
-    print!("Day 5!!\n");
-    let file_path = "src/days/inputs/day5.txt";
-    let (rules, updates) = read_input(file_path).expect("Failed to read input");
-    let ans1 = part1(&rules, &updates);
-    let ans2 = part2(&rules, &updates);
-    println!("part1: {}", ans1);
-    println!("part2: {}", ans2);
+use crate::answer::Answer;
+use crate::error::{parse_num, Error, Result};
+use crate::solution::{Meta, Solution};
+
+/// Ordering rules `X|Y` and the page lists of each update.
+type Manual = (Vec<(i32,i32)>, Vec<Vec<i32>>);
+
+pub struct Day05 {
+    rules: Vec<(i32,i32)>,
+    updates: Vec<Vec<i32>>,
 }
 
-fn read_input(file_path: &str) -> io::Result<(Vec<(i32,i32)>, Vec<Vec<i32>>)> {
-    let file = fs::File::open(file_path)?;
-    let reader = io::BufReader::new(file);
+impl Solution for Day05 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 5,
+        variant: None,
+        title: "Print Queue",
+    };
+
+    fn parse(input: &str) -> Result<Self> {
+        let (rules, updates) = read_input(input)?;
+        Ok(Day05 { rules, updates })
+    }
 
-    let mut lines = reader.lines().map(|l| l.unwrap()).collect::<Vec<_>>();
-    let mut split_idx = 0;
-    for (idx, line) in lines.iter().enumerate() {
-        if line.trim().is_empty() {
-            split_idx = idx;
-            break;
-        }
+    fn part1(&self) -> Result<Answer> {
+        Ok(part1(&self.rules, &self.updates).into())
     }
 
+    fn part2(&self) -> Result<Answer> {
+        Ok(part2(&self.rules, &self.updates).into())
+    }
+}
+
+fn read_input(input: &str) -> Result<Manual> {
+    let lines = input.lines().collect::<Vec<_>>();
+    let split_idx = lines
+        .iter()
+        .position(|line| line.trim().is_empty())
+        .ok_or_else(|| Error::parse("a blank line between the rules and the updates"))?;
+
     let rule_lines = &lines[..split_idx];
     let update_lines = &lines[(split_idx+1)..];
 
     let mut rules = Vec::new();
     for line in rule_lines {
         if line.trim().is_empty() { continue; }
-        let parts: Vec<&str> = line.split('|').collect();
-        let x = parts[0].trim().parse::<i32>().unwrap();
-        let y = parts[1].trim().parse::<i32>().unwrap();
-        rules.push((x,y));
+        let (x, y) = line
+            .split_once('|')
+            .ok_or_else(|| Error::unexpected(line, "a rule like 'X|Y'"))?;
+        rules.push((parse_num(x)?, parse_num(y)?));
     }
 
     let mut updates = Vec::new();
     for line in update_lines {
         if line.trim().is_empty() { continue; }
         let nums = line.split(',')
-            .map(|x| x.trim().parse::<i32>().unwrap())
-            .collect::<Vec<_>>();
+            .map(parse_num)
+            .collect::<Result<Vec<_>>>()?;
         updates.push(nums);
     }
 
     Ok((rules, updates))
 }
 
-fn part1(rules: &Vec<(i32,i32)>, updates: &Vec<Vec<i32>>) -> i32 {
+fn part1(rules: &[(i32,i32)], updates: &[Vec<i32>]) -> i32 {
     let mut sum = 0;
     for update in updates {
         if update_correct(update, rules) {
@@ -60,7 +76,7 @@ fn part1(rules: &Vec<(i32,i32)>, updates: &Vec<Vec<i32>>) -> i32 {
     sum
 }
 
-fn part2(rules: &Vec<(i32,i32)>, updates: &Vec<Vec<i32>>) -> i32 {
+fn part2(rules: &[(i32,i32)], updates: &[Vec<i32>]) -> i32 {
     // We only sum the middle page numbers of the incorrectly-ordered updates AFTER sorting them into the correct order.
     let mut sum = 0;
     for update in updates {
@@ -76,7 +92,7 @@ fn part2(rules: &Vec<(i32,i32)>, updates: &Vec<Vec<i32>>) -> i32 {
     sum
 }
 
-fn correct_order(update: &Vec<i32>, rules: &Vec<(i32,i32)>) -> Vec<i32> {
+fn correct_order(update: &[i32], rules: &[(i32,i32)]) -> Vec<i32> {
     // Build a directed graph from the rules that apply to this update
     let pages: HashSet<i32> = update.iter().cloned().collect();
 
@@ -121,7 +137,7 @@ fn correct_order(update: &Vec<i32>, rules: &Vec<(i32,i32)>) -> Vec<i32> {
     result
 }
 
-fn update_correct(update: &Vec<i32>, rules: &Vec<(i32,i32)>) -> bool {
+fn update_correct(update: &[i32], rules: &[(i32,i32)]) -> bool {
     for &(x,y) in rules {
         if let (Some(ix), Some(iy)) = (update.iter().position(|&v| v==x), update.iter().position(|&v| v==y)) {
             if ix > iy {
@@ -131,3 +147,59 @@ fn update_correct(update: &Vec<i32>, rules: &Vec<(i32,i32)>) -> bool {
     }
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{Error, Location};
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn example() {
+        let day = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1().unwrap(), Answer::Int(143));
+        assert_eq!(day.part2().unwrap(), Answer::Int(123));
+    }
+
+    #[test]
+    fn bad_rule_is_located() {
+        let input = "47|53\n97x13\n\n75,47\n";
+        match Day05::parse(input).map_err(|err| err.locate(input)) {
+            Err(Error::Parse(err)) => {
+                assert_eq!(err.location, Some(Location { line: 2, column: 1 }));
+                assert_eq!(err.found.as_deref(), Some("97x13"));
+            }
+            _ => panic!("expected a parse error"),
+        }
+    }
+}
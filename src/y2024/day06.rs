@@ -1,50 +1,62 @@
-use std::fs;
-use std::io::{self, BufRead};
-use std::collections::{HashSet, HashMap};
-
-pub fn run() {
-    println!("Day 6!!");
-    let file_path = "src/days/inputs/day6.txt";
-    let mut grid = read_input(file_path).expect("Failed to read input");
-    let ans1 = part1(&mut grid);
-    let ans2 = part2(&mut grid);
-    println!("part1: {}", ans1);
-    println!("part2: {}", ans2);
+use std::collections::HashSet;
+
+use crate::answer::Answer;
+use crate::cancel;
+use crate::error::{Error, Result};
+use crate::solution::{Meta, Solution};
+
+pub struct Day06 {
+    grid: Vec<Vec<char>>,
 }
 
-fn read_input(file_path: &str) -> io::Result<Vec<Vec<char>>> {
-    let file = fs::File::open(file_path)?;
-    let reader = io::BufReader::new(file);
-    let mut grid = Vec::new();
+impl Solution for Day06 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 6,
+        variant: None,
+        title: "Guard Gallivant",
+    };
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Day06 { grid: read_input(input) })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(part1(&self.grid)?.into())
+    }
 
-    for line in reader.lines() {
-        let line = line?;
-        grid.push(line.chars().collect());
+    fn part2(&self) -> Result<Answer> {
+        // part2 places obstructions in the grid, so it works on its own copy
+        let mut grid = self.grid.clone();
+        Ok(part2(&mut grid)?.into())
     }
+}
 
-    Ok(grid)
+fn read_input(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
 }
 
-fn part1(grid: &mut [Vec<char>]) -> usize {
+fn part1(grid: &[Vec<char>]) -> Result<usize> {
     // Count distinct visited positions from initial scenario (no added obstruction)
-    let (sx, sy, dir) = find_guard(grid);
-    let visited_positions = simulate(grid, sx, sy, dir, None);
-    visited_positions.len()
+    let (sx, sy, dir) = find_guard(grid)?;
+    let visited_positions = simulate(grid, sx, sy, dir);
+    Ok(visited_positions.len())
 }
 
-fn part2(grid: &mut [Vec<char>]) -> usize {
+fn part2(grid: &mut [Vec<char>]) -> Result<usize> {
     // We want to find how many positions we can place a new '#' to cause a loop.
     // The new obstruction:
     // 1) Can't be at guard's starting position
     // 2) Must change a '.' cell to '#'
     // If placing this obstruction causes the guard to get stuck in a loop, count it.
 
-    let (sx, sy, sdir) = find_guard(grid);
+    let (sx, sy, sdir) = find_guard(grid)?;
 
     let rows = grid.len();
     let mut count = 0;
 
     for i in 0..rows {
+        cancel::check()?;
         let cols = grid[i].len();
         for j in 0..cols {
             if i == sx && j == sy {
@@ -64,7 +76,7 @@ fn part2(grid: &mut [Vec<char>]) -> usize {
         }
     }
 
-    count
+    Ok(count)
 }
 
 fn causes_loop(grid: &[Vec<char>], sx: usize, sy: usize, sdir: usize) -> bool {
@@ -126,7 +138,7 @@ fn causes_loop(grid: &[Vec<char>], sx: usize, sy: usize, sdir: usize) -> bool {
     }
 }
 
-fn simulate(grid: &[Vec<char>], sx: usize, sy: usize, sdir: usize, obstruction: Option<(usize,usize)>) -> HashSet<(usize,usize)> {
+fn simulate(grid: &[Vec<char>], sx: usize, sy: usize, sdir: usize) -> HashSet<(usize,usize)> {
     // Basic simulation to get visited path from starting conditions
     let mut visited = HashSet::new();
     let mut x = sx;
@@ -172,20 +184,19 @@ fn simulate(grid: &[Vec<char>], sx: usize, sy: usize, sdir: usize, obstruction:
     visited
 }
 
-fn find_guard(grid: &[Vec<char>]) -> (usize, usize, usize) {
-    for i in 0..grid.len() {
-        for j in 0..grid[i].len() {
-            let c = grid[i][j];
+fn find_guard(grid: &[Vec<char>]) -> Result<(usize, usize, usize)> {
+    for (i, row) in grid.iter().enumerate() {
+        for (j, &c) in row.iter().enumerate() {
             match c {
-                '^'=>return (i,j,0),
-                '>'=>return (i,j,1),
-                'v'=>return (i,j,2),
-                '<'=>return (i,j,3),
+                '^'=>return Ok((i,j,0)),
+                '>'=>return Ok((i,j,1)),
+                'v'=>return Ok((i,j,2)),
+                '<'=>return Ok((i,j,3)),
                 _=>{}
             }
         }
     }
-    panic!("No guard found");
+    Err(Error::puzzle("No guard found"))
 }
 
 fn out_of_bounds(grid:&[Vec<char>], x:isize,y:isize)->bool {
@@ -193,3 +204,34 @@ fn out_of_bounds(grid:&[Vec<char>], x:isize,y:isize)->bool {
     if y<0||y>=grid[x as usize].len() as isize {return true}
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn example() {
+        let day = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1().unwrap(), Answer::Int(41));
+        assert_eq!(day.part2().unwrap(), Answer::Int(6));
+    }
+
+    #[test]
+    fn missing_guard_is_an_error() {
+        let day = Day06::parse("....\n.#..\n").unwrap();
+        assert!(day.part1().is_err());
+    }
+}
@@ -1,38 +1,57 @@
-use std::fs;
-use std::io::{self, BufRead};
-
-pub fn run() {
-    println!("Day 7 with concatenation!!");
-    let file_path = "src/days/inputs/day7.txt";
-    let (equations, test_values) = read_input(file_path).expect("Failed to read input");
-
-    let ans1 = part1(&equations, &test_values); // Only + and *
-    let ans2 = part2(&equations, &test_values); // +, *, and ||
-    println!("part1: {}", ans1);
-    println!("part2: {}", ans2);
+use crate::answer::Answer;
+use crate::error::{parse_num, Error, Result};
+use crate::solution::{Meta, Solution};
+
+pub struct Day07 {
+    equations: Vec<Vec<i64>>,
+    test_values: Vec<i64>,
 }
 
-fn read_input(file_path: &str) -> io::Result<(Vec<Vec<i64>>, Vec<i64>)> {
-    let file = fs::File::open(file_path)?;
-    let reader = io::BufReader::new(file);
+impl Solution for Day07 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 7,
+        variant: None,
+        title: "Bridge Repair",
+    };
+
+    fn parse(input: &str) -> Result<Self> {
+        let (equations, test_values) = read_input(input)?;
+        Ok(Day07 { equations, test_values })
+    }
+
+    // Only + and *
+    fn part1(&self) -> Result<Answer> {
+        Ok(part1(&self.equations, &self.test_values).into())
+    }
 
+    // +, *, and ||
+    fn part2(&self) -> Result<Answer> {
+        Ok(part2(&self.equations, &self.test_values).into())
+    }
+}
+
+fn read_input(input: &str) -> Result<(Vec<Vec<i64>>, Vec<i64>)> {
     let mut equations = Vec::new();
     let mut test_values = Vec::new();
 
-    for line in reader.lines() {
-        let line = line?;
+    for line in input.lines() {
         if line.trim().is_empty() {
             continue;
         }
 
         // format: "test_value: n1 n2 n3 ..."
-        let parts: Vec<&str> = line.split(':').collect();
-        let test_value = parts[0].trim().parse::<i64>().unwrap();
-        let numbers_str = parts[1].trim().split_whitespace().collect::<Vec<_>>();
+        let (test_value, numbers_str) = line
+            .split_once(':')
+            .ok_or_else(|| Error::unexpected(line, "an equation like 'value: numbers'"))?;
+        let test_value = parse_num(test_value)?;
         let numbers = numbers_str
-            .iter()
-            .map(|x| x.parse::<i64>().unwrap())
-            .collect::<Vec<_>>();
+            .split_whitespace()
+            .map(parse_num)
+            .collect::<Result<Vec<i64>>>()?;
+        if numbers.is_empty() {
+            return Err(Error::unexpected(line, "numbers after the test value"));
+        }
 
         test_values.push(test_value);
         equations.push(numbers);
@@ -41,7 +60,7 @@ fn read_input(file_path: &str) -> io::Result<(Vec<Vec<i64>>, Vec<i64>)> {
     Ok((equations, test_values))
 }
 
-fn part1(equations: &Vec<Vec<i64>>, test_values: &Vec<i64>) -> i64 {
+fn part1(equations: &[Vec<i64>], test_values: &[i64]) -> i64 {
     // sum of test_values for equations that can be made true using only + and *
     let mut sum = 0;
     for (i, eq) in equations.iter().enumerate() {
@@ -53,7 +72,7 @@ fn part1(equations: &Vec<Vec<i64>>, test_values: &Vec<i64>) -> i64 {
     sum
 }
 
-fn part2(equations: &Vec<Vec<i64>>, test_values: &Vec<i64>) -> i64 {
+fn part2(equations: &[Vec<i64>], test_values: &[i64]) -> i64 {
     // sum of test_values for equations that can be made true using +, *, and ||
     let mut sum = 0;
     for (i, eq) in equations.iter().enumerate() {
@@ -65,7 +84,7 @@ fn part2(equations: &Vec<Vec<i64>>, test_values: &Vec<i64>) -> i64 {
     sum
 }
 
-fn can_make_true_basic(nums: &Vec<i64>, target: i64) -> bool {
+fn can_make_true_basic(nums: &[i64], target: i64) -> bool {
     // Only + and * operators
     // If nums.len()==1 just check equality
     if nums.len() == 1 {
@@ -95,7 +114,7 @@ fn can_make_true_basic(nums: &Vec<i64>, target: i64) -> bool {
     false
 }
 
-fn can_make_true_all_ops(nums: &Vec<i64>, target: i64) -> bool {
+fn can_make_true_all_ops(nums: &[i64], target: i64) -> bool {
     // Now we have +, *, and |
     // We'll use backtracking as before, but now we have 3 choices per gap
     if nums.len() == 1 {
@@ -127,8 +146,8 @@ fn evaluate(nums: &[i64], ops: &[char]) -> i64 {
     for (i, &op) in ops.iter().enumerate() {
         let nxt = nums[i+1];
         match op {
-            '+' => val = val + nxt,
-            '*' => val = val * nxt,
+            '+' => val += nxt,
+            '*' => val *= nxt,
             '|' => val = concat_numbers(val, nxt),
             _ => {}
         }
@@ -146,3 +165,27 @@ fn concat_numbers(a: i64, b: i64) -> i64 {
     }
     a * (mag*10) + b
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn example() {
+        let day = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1().unwrap(), Answer::Int(3749));
+        assert_eq!(day.part2().unwrap(), Answer::Int(11387));
+    }
+}
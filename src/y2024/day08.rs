@@ -1,20 +1,39 @@
 use std::collections::HashSet;
-use std::fs;
 
-pub fn run() {
-    println!("Day 8!!");
-    let file_path = "src/days/inputs/day8.txt";
-    let grid = read_input(file_path);
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::solution::{Meta, Solution};
 
-    let ans1 = part1(&grid);
-    println!("part1: {}", ans1);
+pub struct Day08 {
+    grid: Vec<Vec<char>>,
+}
+
+impl Solution for Day08 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 8,
+        variant: None,
+        title: "Resonant Collinearity",
+    };
+
+    fn parse(input: &str) -> Result<Self> {
+        let grid = read_input(input);
+        if grid.is_empty() {
+            return Err(Error::parse("a non-empty antenna map"));
+        }
+        Ok(Day08 { grid })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(part1(&self.grid).into())
+    }
 
-    let ans2 = part2(&grid);
-    println!("part2: {}", ans2);
+    fn part2(&self) -> Result<Answer> {
+        Ok(part2(&self.grid).into())
+    }
 }
 
-fn read_input(file_path: &str) -> Vec<Vec<char>> {
-    let input = fs::read_to_string(file_path).expect("Failed to read input file");
+fn read_input(input: &str) -> Vec<Vec<char>> {
     input.lines().map(|line| line.chars().collect()).collect()
 }
 
@@ -108,3 +127,30 @@ fn in_bounds(pos: (isize, isize), rows: usize, cols: usize) -> bool {
     let (i, j) = pos;
     i >= 0 && i < rows as isize && j >= 0 && j < cols as isize
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+    #[test]
+    fn example() {
+        let day = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1().unwrap(), Answer::Int(14));
+        assert_eq!(day.part2().unwrap(), Answer::Int(34));
+    }
+}
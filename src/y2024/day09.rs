@@ -1,4 +1,7 @@
-use std::fs;
+use crate::answer::Answer;
+use crate::cancel;
+use crate::error::{Error, Result};
+use crate::solution::{Meta, Solution};
 
 #[derive(Clone, Debug)]
 enum Sector {
@@ -6,19 +9,22 @@ enum Sector {
     FreeSpace,     // Represents free space
 }
 
+#[derive(Clone)]
 struct Filesystem {
     sectors: Vec<Sector>,
 }
 
 impl Filesystem {
-    fn new(file_path: &str) -> Self {
-        let input = fs::read_to_string(file_path).expect("Failed to read input file");
+    fn new(input: &str) -> Result<Self> {
         let mut sectors = Vec::new();
         let mut is_file = true;
         let mut file_id = 0;
 
-        for c in input.trim().chars() {
-            let length = c.to_digit(10).expect("Invalid input");
+        let input = input.trim();
+        for (i, c) in input.char_indices() {
+            let length = c
+                .to_digit(10)
+                .ok_or_else(|| Error::unexpected(&input[i..i + c.len_utf8()], "a digit in the disk map"))?;
             if is_file {
                 sectors.extend(vec![Sector::File(file_id); length as usize]);
                 file_id += 1;
@@ -28,7 +34,11 @@ impl Filesystem {
             is_file = !is_file;
         }
 
-        Filesystem { sectors }
+        if sectors.is_empty() {
+            return Err(Error::parse("a non-empty disk map"));
+        }
+
+        Ok(Filesystem { sectors })
     }
 
     fn get_checksum(&self) -> u64 {
@@ -64,8 +74,9 @@ impl PerBlockDefragmenter {
         false
     }
 
-    fn defragment(filesystem: &mut Filesystem) {
+    fn defragment(filesystem: &mut Filesystem) -> Result<()> {
         while Self::gaps_exist(filesystem) {
+            cancel::check()?;
             let free_space = filesystem
                 .sectors
                 .iter()
@@ -89,6 +100,7 @@ impl PerBlockDefragmenter {
                 }
             }
         }
+        Ok(())
     }
 }
 
@@ -127,10 +139,11 @@ impl PerFileDefragmenter {
         true
     }
 
-    fn defragment(filesystem: &mut Filesystem) {
+    fn defragment(filesystem: &mut Filesystem) -> Result<()> {
         let file_map = Self::build_file_map(filesystem);
 
-        for (file_id, indexes, size) in file_map {
+        for (_, indexes, size) in file_map {
+            cancel::check()?;
             let required_space = size;
             let first_file_index = *indexes.iter().min().unwrap();
 
@@ -147,22 +160,55 @@ impl PerFileDefragmenter {
                 }
             }
         }
+        Ok(())
     }
 }
 
-pub fn run() {
-    println!("Day 9!!");
-    let file_path = "src/days/inputs/day9.txt";
+pub struct Day09 {
+    filesystem: Filesystem,
+}
+
+impl Solution for Day09 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 9,
+        variant: None,
+        title: "Disk Fragmenter",
+    };
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Day09 { filesystem: Filesystem::new(input)? })
+    }
 
     // Part 1: Block-by-block defragmentation
-    let mut filesystem_part1 = Filesystem::new(file_path);
-    PerBlockDefragmenter::defragment(&mut filesystem_part1);
-    let checksum_part1 = filesystem_part1.get_checksum();
-    println!("Part 1 Checksum: {}", checksum_part1);
+    fn part1(&self) -> Result<Answer> {
+        let mut filesystem = self.filesystem.clone();
+        PerBlockDefragmenter::defragment(&mut filesystem)?;
+        Ok(filesystem.get_checksum().into())
+    }
 
     // Part 2: File-by-file defragmentation
-    let mut filesystem_part2 = Filesystem::new(file_path);
-    PerFileDefragmenter::defragment(&mut filesystem_part2);
-    let checksum_part2 = filesystem_part2.get_checksum();
-    println!("Part 2 Checksum: {}", checksum_part2);
+    fn part2(&self) -> Result<Answer> {
+        let mut filesystem = self.filesystem.clone();
+        PerFileDefragmenter::defragment(&mut filesystem)?;
+        Ok(filesystem.get_checksum().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let day = Day09::parse("2333133121414131402\n").unwrap();
+        assert_eq!(day.part1().unwrap(), Answer::Int(1928));
+        assert_eq!(day.part2().unwrap(), Answer::Int(2858));
+    }
+
+    #[test]
+    fn rejects_non_digits() {
+        assert!(Day09::parse("23x3\n").is_err());
+        assert!(Day09::parse("\n").is_err());
+    }
 }
@@ -1,25 +1,46 @@
 use std::collections::{HashSet, VecDeque};
-use std::fs;
 
-pub fn run() {
-    println!("Day 10!!");
-    let file_path = "src/days/inputs/day10.txt";
-    let map = read_input(file_path);
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::solution::{Meta, Solution};
 
-    let total_score = calculate_total_trailhead_score(&map);
-    println!("Total trailhead score: {}", total_score);
+pub struct Day10 {
+    map: Vec<Vec<u32>>,
+}
 
-    println!("Day 10 - Part 2!!");
+impl Solution for Day10 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 10,
+        variant: None,
+        title: "Hoof It",
+    };
+
+    fn parse(input: &str) -> Result<Self> {
+        let map = read_input(input)?;
+        if map.is_empty() {
+            return Err(Error::parse("a non-empty topographic map"));
+        }
+        Ok(Day10 { map })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(calculate_total_trailhead_score(&self.map).into())
+    }
 
-    let total_rating = calculate_total_trailhead_rating(&map);
-    println!("Total trailhead rating: {}", total_rating);
+    fn part2(&self) -> Result<Answer> {
+        Ok(calculate_total_trailhead_rating(&self.map).into())
+    }
 }
 
-fn read_input(file_path: &str) -> Vec<Vec<u32>> {
-    let input = fs::read_to_string(file_path).expect("Failed to read input file");
+fn read_input(input: &str) -> Result<Vec<Vec<u32>>> {
     input
         .lines()
-        .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
+        .map(|line| {
+            line.char_indices()
+                .map(|(i, c)| c.to_digit(10).ok_or_else(|| Error::unexpected(&line[i..i + c.len_utf8()], "a height digit")))
+                .collect()
+        })
         .collect()
 }
 
@@ -111,7 +132,7 @@ fn count_paths_to_nines(
     x: usize,
     y: usize,
     current_height: u32,
-    visited: &mut Vec<Vec<bool>>,
+    visited: &mut [Vec<bool>],
 ) -> usize {
     let rows = map.len();
     let cols = map[0].len();
@@ -152,4 +173,27 @@ fn count_paths_to_nines(
     visited[x][y] = false;
 
     path_count
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    #[test]
+    fn example() {
+        let day = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1().unwrap(), Answer::Int(36));
+        assert_eq!(day.part2().unwrap(), Answer::Int(81));
+    }
+}
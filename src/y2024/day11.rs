@@ -1,31 +1,45 @@
 use std::collections::VecDeque;
-use std::fs;
 use std::collections::HashMap;
 
+use crate::answer::Answer;
+use crate::error::{parse_num, Result};
+use crate::params::Param;
+use crate::solution::{Meta, Solution};
 
-pub fn run() {
-    println!("Day 11 - Plutonian Pebbles!!");
-    let file_path = "src/days/inputs/day11.txt";
-    let initial_stones = read_input(file_path);
-    let blinks = 25;
+const BLINKS_PART1: Param = Param::new("blinks1", 25, "times the stones change in part 1");
+const BLINKS_PART2: Param = Param::new("blinks2", 75, "times the stones change in part 2");
 
-    let total_stones = simulate_stones(&initial_stones, blinks);
-    println!("Total stones after {} blinks: {}", blinks, total_stones);
+pub struct Day11 {
+    initial_stones: Vec<u64>,
+}
+
+impl Solution for Day11 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 11,
+        variant: None,
+        title: "Plutonian Pebbles",
+    };
+    const PARAMS: &'static [Param] = &[BLINKS_PART1, BLINKS_PART2];
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Day11 { initial_stones: read_input(input)? })
+    }
 
-    println!("Day 11 - Plutonian Pebbles!! Part 2");
-    let blinks = 75;
+    fn part1(&self) -> Result<Answer> {
+        Ok(simulate_stones(&self.initial_stones, BLINKS_PART1.get()?).into())
+    }
 
-    let total_stones = simulate_stones_optimized(&initial_stones, blinks);
-    println!("Total stones after {} blinks: {}", blinks, total_stones);
+    fn part2(&self) -> Result<Answer> {
+        Ok(simulate_stones_optimized(&self.initial_stones, BLINKS_PART2.get()?).into())
+    }
 }
 
-// Read input file and parse numbers as u64
-fn read_input(file_path: &str) -> Vec<u64> {
-    let input = fs::read_to_string(file_path).expect("Failed to read input file");
+// Parse the input numbers as u64
+fn read_input(input: &str) -> Result<Vec<u64>> {
     input
-        .trim()
         .split_whitespace()
-        .map(|num| num.parse::<u64>().expect("Invalid number in input file"))
+        .map(parse_num)
         .collect()
 }
 
@@ -56,7 +70,7 @@ fn simulate_stones(initial: &[u64], blinks: usize) -> usize {
 
 fn has_even_digits(num: u64) -> bool {
     let digits = num.to_string().len();
-    digits % 2 == 0
+    digits.is_multiple_of(2)
 }
 
 fn split_number(num: u64) -> (u64, u64) {
@@ -101,3 +115,21 @@ fn simulate_stones_optimized(initial: &[u64], blinks: usize) -> u64 {
     // Sum up total stones
     stone_count.values().sum()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let day = Day11::parse("125 17\n").unwrap();
+        assert_eq!(day.part1().unwrap(), Answer::Int(55312));
+    }
+
+    #[test]
+    fn optimized_matches_naive() {
+        let stones = read_input("125 17").unwrap();
+        assert_eq!(simulate_stones(&stones, 6), 22);
+        assert_eq!(simulate_stones_optimized(&stones, 25), 55312);
+    }
+}
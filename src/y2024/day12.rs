@@ -1,22 +1,40 @@
 use std::collections::{HashSet, VecDeque};
-use std::fs;
 
-pub fn run() {
-    println!("Day 12 - Garden Groups!!");
-    let file_path = "src/days/inputs/day12.txt";
-    let grid = read_input(file_path);
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::log;
+use crate::solution::{Meta, Solution};
 
-    let total_price = calculate_total_price(&grid);
-    println!("Total price of fencing: {}", total_price);
+pub struct Day12 {
+    grid: Vec<Vec<char>>,
+}
+
+impl Solution for Day12 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 12,
+        variant: None,
+        title: "Garden Groups",
+    };
+
+    fn parse(input: &str) -> Result<Self> {
+        let grid = read_input(input);
+        if grid.is_empty() {
+            return Err(Error::parse("a non-empty garden map"));
+        }
+        Ok(Day12 { grid })
+    }
 
-    println!("Day 12 - Garden Groups!! (Part 2)");
+    fn part1(&self) -> Result<Answer> {
+        Ok(calculate_total_price(&self.grid).into())
+    }
 
-    let total_price = calculate_total_price2(&grid);
-    println!("New total price of fencing: {}", total_price);
+    fn part2(&self) -> Result<Answer> {
+        Ok(calculate_total_price2(&self.grid).into())
+    }
 }
 
-fn read_input(file_path: &str) -> Vec<Vec<char>> {
-    let input = fs::read_to_string(file_path).expect("Failed to read input file");
+fn read_input(input: &str) -> Vec<Vec<char>> {
     input
         .lines()
         .map(|line| line.chars().collect())
@@ -35,7 +53,7 @@ fn calculate_total_price(grid: &[Vec<char>]) -> u32 {
         for j in 0..cols {
             if !visited[i][j] {
                 let plant_type = grid[i][j];
-                let (area, perimeter) = flood_fill_and_calculate(&grid, &mut visited, i, j, plant_type, &directions);
+                let (area, perimeter) = flood_fill_and_calculate(grid, &mut visited, i, j, plant_type, &directions);
                 let price = area * perimeter;
                 total_price += price;
             }
@@ -47,7 +65,7 @@ fn calculate_total_price(grid: &[Vec<char>]) -> u32 {
 
 fn flood_fill_and_calculate(
     grid: &[Vec<char>],
-    visited: &mut Vec<Vec<bool>>,
+    visited: &mut [Vec<bool>],
     start_x: usize,
     start_y: usize,
     plant_type: char,
@@ -127,7 +145,7 @@ fn calculate_total_price2(grid: &[Vec<char>]) -> u32 {
         for j in 0..cols {
             if !visited[i][j] {
                 let plant_type = grid[i][j];
-                let (area, sides) = flood_fill_and_count_sides(&grid, &mut visited, i, j, plant_type, &directions);
+                let (area, sides) = flood_fill_and_count_sides(grid, &mut visited, i, j, plant_type, &directions);
                 let price = area * sides;
                 total_price += price;
             }
@@ -139,7 +157,7 @@ fn calculate_total_price2(grid: &[Vec<char>]) -> u32 {
 
 fn flood_fill_and_count_sides(
     grid: &[Vec<char>],
-    visited: &mut Vec<Vec<bool>>,
+    visited: &mut [Vec<bool>],
     start_x: usize,
     start_y: usize,
     plant_type: char,
@@ -203,10 +221,7 @@ fn count_sides(
                 && !region_cells.contains(&(nw.0 as usize, nw.1 as usize));
             if !same_edge {
                 sides += 1;
-                println!(
-                    "North edge counted for cell ({}, {}) | Plant type '{}'",
-                    r, c, plant_type
-                );
+                log::trace!("North edge counted for cell ({}, {}) | Plant type '{}'", r, c, plant_type);
             }
         }
 
@@ -216,10 +231,7 @@ fn count_sides(
                 && !region_cells.contains(&(sw.0 as usize, sw.1 as usize));
             if !same_edge {
                 sides += 1;
-                println!(
-                    "South edge counted for cell ({}, {}) | Plant type '{}'",
-                    r, c, plant_type
-                );
+                log::trace!("South edge counted for cell ({}, {}) | Plant type '{}'", r, c, plant_type);
             }
         }
 
@@ -229,10 +241,7 @@ fn count_sides(
                 && !region_cells.contains(&(nw.0 as usize, nw.1 as usize));
             if !same_edge {
                 sides += 1;
-                println!(
-                    "West edge counted for cell ({}, {}) | Plant type '{}'",
-                    r, c, plant_type
-                );
+                log::trace!("West edge counted for cell ({}, {}) | Plant type '{}'", r, c, plant_type);
             }
         }
 
@@ -242,14 +251,50 @@ fn count_sides(
                 && !region_cells.contains(&(ne.0 as usize, ne.1 as usize));
             if !same_edge {
                 sides += 1;
-                println!(
-                    "East edge counted for cell ({}, {}) | Plant type '{}'",
-                    r, c, plant_type
-                );
+                log::trace!("East edge counted for cell ({}, {}) | Plant type '{}'", r, c, plant_type);
             }
         }
     }
 
-    println!("Total sides for plant type '{}': {}", plant_type, sides);
+    log::debug!("Total sides for plant type '{}': {}", plant_type, sides);
     sides
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC
+";
+
+    const LARGE: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+
+    #[test]
+    fn small_example() {
+        let day = Day12::parse(SMALL).unwrap();
+        assert_eq!(day.part1().unwrap(), Answer::Int(140));
+        assert_eq!(day.part2().unwrap(), Answer::Int(80));
+    }
+
+    #[test]
+    fn large_example() {
+        let day = Day12::parse(LARGE).unwrap();
+        assert_eq!(day.part1().unwrap(), Answer::Int(1930));
+        assert_eq!(day.part2().unwrap(), Answer::Int(1206));
+    }
+}
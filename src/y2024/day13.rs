@@ -0,0 +1,160 @@
+use std::collections::HashSet;
+
+use crate::answer::Answer;
+use crate::error::{parse_num, Error, Result};
+use crate::params::Param;
+use crate::solution::{Meta, Solution};
+
+/// Button A, button B and prize coordinates of one claw machine.
+type Machine = ((i64, i64), (i64, i64), (i64, i64));
+
+// Keeps the determinant products within an `i64` for two-digit button moves
+const CORRECTION: Param = Param::new("correction", 10_000_000_000_000, "offset added to each prize position in part 2")
+    .between(0, 10_000_000_000_000_000);
+
+pub struct Day13 {
+    machines: HashSet<Machine>,
+}
+
+impl Solution for Day13 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 13,
+        variant: None,
+        title: "Claw Contraption",
+    };
+    const PARAMS: &'static [Param] = &[CORRECTION];
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Day13 { machines: read_input(input)? })
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        // Apply correction for Part 2
+        let corrected_machines = apply_prize_correction(&self.machines, CORRECTION.get()?);
+
+        let (_, total_cost_part2) = calculate_tokens_optimized(&corrected_machines);
+        Ok(total_cost_part2.into())
+    }
+}
+
+fn read_input(input: &str) -> Result<HashSet<Machine>> {
+    let mut machines = HashSet::new();
+
+    for chunk in input.split("\n\n").filter(|chunk| !chunk.trim().is_empty()) {
+        let lines: Vec<&str> = chunk.lines().collect();
+        if lines.len() < 3 {
+            return Err(Error::unexpected(chunk, "three lines per machine"));
+        }
+
+        let button_a = parse_pair(lines[0], "Button A: ", "X+", "Y+")?;
+        let button_b = parse_pair(lines[1], "Button B: ", "X+", "Y+")?;
+        let prize = parse_pair(lines[2], "Prize: ", "X=", "Y=")?;
+
+        machines.insert((button_a, button_b, prize));
+    }
+
+    Ok(machines)
+}
+
+/// Parses a line like `Button A: X+94, Y+34` or `Prize: X=8400, Y=5400`.
+fn parse_pair(line: &str, prefix: &str, x_prefix: &str, y_prefix: &str) -> Result<(i64, i64)> {
+    let expected = || Error::unexpected(line, format!("'{}{}.., {}..'", prefix, x_prefix, y_prefix));
+    let rest = line.strip_prefix(prefix).ok_or_else(expected)?;
+    let (x, y) = rest.split_once(", ").ok_or_else(expected)?;
+    let x = x.strip_prefix(x_prefix).ok_or_else(expected)?;
+    let y = y.strip_prefix(y_prefix).ok_or_else(expected)?;
+    Ok((parse_num(x)?, parse_num(y)?))
+}
+
+fn apply_prize_correction(
+    machines: &HashSet<Machine>,
+    correction: i64,
+) -> HashSet<Machine> {
+    machines
+        .iter()
+        .map(|&(button_a, button_b, (px, py))| (button_a, button_b, (px + correction, py + correction)))
+        .collect()
+}
+
+fn calculate_tokens_optimized(
+    machines: &HashSet<Machine>,
+) -> (usize, i64) {
+    let mut prizes_won = 0;
+    let mut total_cost = 0;
+
+    for &((ax, ay), (bx, by), (px, py)) in machines {
+        if let Some((_, _, cost)) = solve_algebraically(ax, ay, bx, by, px, py) {
+            prizes_won += 1;
+            total_cost += cost;
+        }
+    }
+
+    (prizes_won, total_cost)
+}
+
+fn solve_algebraically(
+    ax: i64,
+    ay: i64,
+    bx: i64,
+    by: i64,
+    px: i64,
+    py: i64,
+) -> Option<(i64, i64, i64)> {
+    // Use modular arithmetic to eliminate x or y
+    let determinant = ax * by - ay * bx;
+
+    if determinant == 0 {
+        return None; // No unique solution
+    }
+
+    // Find a particular solution using the determinant
+    let x_part = (px * by - py * bx) / determinant;
+    let y_part = (py * ax - px * ay) / determinant;
+
+    // Ensure the solution is integer and satisfies both equations
+    if (px - ax * x_part - bx * y_part) == 0 && (py - ay * x_part - by * y_part) == 0 {
+        let cost = 3 * x_part + y_part;
+        if x_part >= 0 && y_part >= 0 {
+            return Some((x_part, y_part, cost));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+    #[test]
+    fn example_without_correction() {
+        let machines = read_input(EXAMPLE).unwrap();
+        assert_eq!(calculate_tokens_optimized(&machines), (2, 480));
+    }
+
+    #[test]
+    fn example_with_correction() {
+        // Only the second and fourth machines can be won once the prizes move
+        let machines = apply_prize_correction(&read_input(EXAMPLE).unwrap(), 10_000_000_000_000);
+        assert_eq!(calculate_tokens_optimized(&machines).0, 2);
+    }
+}
@@ -0,0 +1,138 @@
+use crate::answer::Answer;
+use crate::error::{parse_num, Error, Result};
+use crate::params::Param;
+use crate::solution::{Meta, Solution};
+
+/// Position and velocity of one robot.
+type Robot = ((i32, i32), (i32, i32));
+
+pub const WIDTH: Param = Param::new("width", 101, "width of the space the robots move in").between(1, 10_000);
+pub const HEIGHT: Param = Param::new("height", 103, "height of the space the robots move in").between(1, 10_000);
+// Keeps `position + velocity * seconds` within an `i64` for any `i32` position and velocity
+const SECONDS: Param =
+    Param::new("seconds", 100, "seconds to simulate before computing the safety factor").between(0, 1_000_000);
+
+pub struct Day14 {
+    robots: Vec<Robot>,
+}
+
+impl Solution for Day14 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 14,
+        variant: None,
+        title: "Restroom Redoubt",
+    };
+    const PARAMS: &'static [Param] = &[WIDTH, HEIGHT, SECONDS];
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Day14 { robots: read_input(input)? })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let width = WIDTH.get()?;
+        let height = HEIGHT.get()?;
+        let time = SECONDS.get()?;
+
+        let positions = simulate_positions(&self.robots, width, height, time);
+        Ok(calculate_safety_factor(&positions, width, height).into())
+    }
+}
+
+fn read_input(input: &str) -> Result<Vec<Robot>> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let expected = || Error::unexpected(line, "a robot like 'p=0,4 v=3,-3'");
+            let (pos, vel) = line.split_once(' ').ok_or_else(expected)?;
+            let pos = pos.strip_prefix("p=").ok_or_else(expected)?;
+            let vel = vel.trim().strip_prefix("v=").ok_or_else(expected)?;
+            let (px, py) = pos.split_once(',').ok_or_else(expected)?;
+            let (vx, vy) = vel.split_once(',').ok_or_else(expected)?;
+            Ok(((parse_num(px)?, parse_num(py)?), (parse_num(vx)?, parse_num(vy)?)))
+        })
+        .collect()
+}
+
+fn simulate_positions(
+    robots: &[Robot],
+    width: i32,
+    height: i32,
+    time: i32,
+) -> Vec<(i32, i32)> {
+    robots
+        .iter()
+        .map(|&((x, y), (vx, vy))| {
+            (
+                wrap(x, vx, time, width),
+                wrap(y, vy, time, height),
+            )
+        })
+        .collect()
+}
+
+/// Where a robot at `p` moving `v` per second is after `time` seconds, in a room `size` wide.
+fn wrap(p: i32, v: i32, time: i32, size: i32) -> i32 {
+    (i64::from(p) + i64::from(v) * i64::from(time)).rem_euclid(i64::from(size)) as i32
+}
+
+fn calculate_safety_factor(positions: &[(i32, i32)], width: i32, height: i32) -> i32 {
+    let mid_x = width / 2;
+    let mid_y = height / 2;
+
+    let mut quadrants = [0, 0, 0, 0]; // Q1, Q2, Q3, Q4
+
+    for &(x, y) in positions {
+        if x == mid_x || y == mid_y {
+            continue; // Exclude robots in the middle rows or columns
+        }
+
+        if x < mid_x && y < mid_y {
+            quadrants[0] += 1; // Q1
+        } else if x > mid_x && y < mid_y {
+            quadrants[1] += 1; // Q2
+        } else if x < mid_x && y > mid_y {
+            quadrants[2] += 1; // Q3
+        } else if x > mid_x && y > mid_y {
+            quadrants[3] += 1; // Q4
+        }
+    }
+
+    quadrants.iter().product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::params;
+
+    const EXAMPLE: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+    #[test]
+    fn example() {
+        // The example room is 11 tiles wide and 7 tall
+        let overrides = [("width".to_string(), 11), ("height".to_string(), 7)];
+        let answer = params::with_overrides(&overrides, || Day14::parse(EXAMPLE)?.part1());
+        assert_eq!(answer.unwrap(), Answer::Int(12));
+    }
+
+    #[test]
+    fn robots_wrap_around() {
+        let robots = read_input("p=2,4 v=2,-3\n").unwrap();
+        assert_eq!(simulate_positions(&robots, 11, 7, 5), vec![(1, 3)]);
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::HashSet;
+use std::io;
+use std::path::Path;
+use regex::Regex;
+use image::{RgbImage, Rgb};
+
+use crate::answer::Answer;
+use crate::config;
+use crate::error::{parse_num, Error, Result};
+use crate::params::Param;
+use crate::solution::{Meta, Solution};
+use crate::y2024::day14::{HEIGHT, WIDTH};
+
+/// Width and height of the space the robots move in.
+type Room = (i32, i32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Robot {
+    x: i32,
+    y: i32,
+    vx: i32,
+    vy: i32,
+}
+
+impl Robot {
+    fn move_n(&self, n: i32, (width, height): Room) -> Robot {
+        // Any i32 velocity times up to width * height seconds fits in an i64
+        let step = |p: i32, v: i32, size: i32| {
+            (i64::from(p) + i64::from(v) * i64::from(n)).rem_euclid(i64::from(size)) as i32
+        };
+        Robot {
+            x: step(self.x, self.vx, width),
+            y: step(self.y, self.vy, height),
+            vx: self.vx,
+            vy: self.vy,
+        }
+    }
+}
+
+fn read_input(input: &str) -> Result<Vec<Robot>> {
+    let robo_re = Regex::new(r"^p=(-?\d+),(-?\d+) v=(-?\d+),(-?\d+)$").unwrap();
+
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let cap = robo_re
+                .captures(line)
+                .ok_or_else(|| Error::unexpected(line, "a robot like 'p=0,4 v=3,-3'"))?;
+            let num = |i: usize| parse_num(cap.get(i).map_or("", |m| m.as_str()));
+            Ok(Robot { x: num(1)?, y: num(2)?, vx: num(3)?, vy: num(4)? })
+        })
+        .collect()
+}
+
+fn find_min_unique_positions(robots: &[Robot], room: Room) -> Option<i32> {
+    // The robots are back where they started after width * height seconds
+    for t in 0..room.0 * room.1 {
+        let mut positions = HashSet::new();
+        let mut duplicate_found = false;
+
+        for robot in robots {
+            let moved = robot.move_n(t, room);
+            if !positions.insert((moved.x, moved.y)) {
+                duplicate_found = true;
+                break;
+            }
+        }
+
+        if !duplicate_found {
+            return Some(t); // All positions are unique at this time
+        }
+    }
+    None
+}
+
+fn save_image(robots: &[Robot], second: i32, room: Room, path: &Path) -> Result<()> {
+    let mut img = RgbImage::new(room.0 as u32, room.1 as u32);
+    let white = Rgb([255, 255, 255]);
+    let black = Rgb([0, 0, 0]);
+
+    // Fill the image with black pixels
+    for pixel in img.pixels_mut() {
+        *pixel = black;
+    }
+
+    // Set robot positions to white
+    for robot in robots {
+        let moved = robot.move_n(second, room);
+        img.put_pixel(moved.x as u32, moved.y as u32, white);
+    }
+
+    // Save the image
+    img.save(path)
+        .map_err(|err| Error::Io(io::Error::other(format!("failed to save {}: {}", path.display(), err))))
+}
+
+pub struct Day14p2 {
+    robots: Vec<Robot>,
+}
+
+impl Solution for Day14p2 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 14,
+        variant: Some("p2"),
+        title: "Restroom Redoubt (Easter egg)",
+    };
+    const PARAMS: &'static [Param] = &[WIDTH, HEIGHT];
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Day14p2 { robots: read_input(input)? })
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let output_image = config::artifact_path("robots_image.png")?;
+        let room = (WIDTH.get()?, HEIGHT.get()?);
+        let time = find_min_unique_positions(&self.robots, room)
+            .ok_or_else(|| Error::puzzle("the robots never all stand on distinct tiles"))?;
+
+        // Keep a picture of the arrangement so the tree can be checked by eye
+        save_image(&self.robots, time, room, &output_image)?;
+        Ok(time.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distinct_from_the_start() {
+        let robots = read_input("p=0,0 v=1,1\np=5,5 v=1,1\n").unwrap();
+        assert_eq!(find_min_unique_positions(&robots, (101, 103)), Some(0));
+    }
+
+    #[test]
+    fn fast_robots_wrap_without_overflowing() {
+        let robot = Robot { x: 0, y: 0, vx: i32::MAX, vy: i32::MIN };
+        let moved = robot.move_n(10_000 * 10_000 - 1, (10_000, 10_000));
+        assert_eq!((moved.x, moved.y), (6_353, 3_648));
+    }
+
+    #[test]
+    fn rejects_malformed_robots() {
+        assert!(Day14p2::parse("p=0,4 v=3,-3\np=6,3\n").is_err());
+    }
+}
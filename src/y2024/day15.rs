@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::solution::{Meta, Solution};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Point {
     x: i32,
@@ -43,15 +47,35 @@ impl std::ops::AddAssign for Point {
     }
 }
 
-pub fn run() {
-    let input = std::fs::read_to_string("src/days/inputs/day15.txt").expect("Failed to read input file");
-    
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+pub struct Day15 {
+    input: String,
+}
+
+impl Solution for Day15 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 15,
+        variant: None,
+        title: "Warehouse Woes",
+    };
+
+    fn parse(input: &str) -> Result<Self> {
+        // Validate up front so the parts only have to deal with well-formed input
+        parse(input, 1)?;
+        Ok(Day15 { input: input.to_string() })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(part1(&self.input)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(part2(&self.input)?.into())
+    }
 }
 
-fn part1(input: &str) -> usize {
-    let (mut grid, moves, mut robot) = parse(input, 1);
+fn part1(input: &str) -> Result<usize> {
+    let (mut grid, moves, mut robot) = parse(input, 1)?;
 
     for &m in &moves {
         let direction = Point::from(m);
@@ -84,11 +108,11 @@ fn part1(input: &str) -> usize {
         }
     }
 
-    coordinates(&grid)
+    Ok(coordinates(&grid))
 }
 
-fn part2(input: &str) -> usize {
-    let (mut grid, moves, mut robot) = parse(input, 2);
+fn part2(input: &str) -> Result<usize> {
+    let (mut grid, moves, mut robot) = parse(input, 2)?;
 
     for &m in &moves {
         let direction = Point::from(m);
@@ -125,18 +149,16 @@ fn part2(input: &str) -> usize {
                                         next_boxes.clear();
                                         break;
                                     }
-                                    side @ b'[' | side @ b']' => {
-                                        if !next_boxes.contains(&path) {
-                                            boxes.push(path);
-                                            next_boxes.push(path);
-
-                                            if side == b'[' {
-                                                boxes.push(path + Point::right());
-                                                next_boxes.push(path + Point::right());
-                                            } else {
-                                                boxes.push(path + Point::left());
-                                                next_boxes.push(path + Point::left());
-                                            }
+                                    side @ b'[' | side @ b']' if !next_boxes.contains(&path) => {
+                                        boxes.push(path);
+                                        next_boxes.push(path);
+
+                                        if side == b'[' {
+                                            boxes.push(path + Point::right());
+                                            next_boxes.push(path + Point::right());
+                                        } else {
+                                            boxes.push(path + Point::left());
+                                            next_boxes.push(path + Point::left());
                                         }
                                     }
                                     _ => {}
@@ -175,14 +197,24 @@ fn part2(input: &str) -> usize {
         }
     }
 
-    coordinates(&grid)
+    Ok(coordinates(&grid))
 }
 
-fn parse(input: &str, part: u8) -> (Vec<Vec<u8>>, Vec<u8>, Point) {
-    let (grid, moves) = input.split_once("\n\n").unwrap();
+fn parse(input: &str, part: u8) -> Result<(Vec<Vec<u8>>, Vec<u8>, Point)> {
+    let (grid, moves) = input
+        .split_once("\n\n")
+        .ok_or_else(|| Error::parse("a blank line between the warehouse map and the moves"))?;
+
+    if let Some((i, c)) = grid.char_indices().find(|&(_, c)| !"#O.@\n".contains(c)) {
+        return Err(Error::unexpected(&grid[i..i + c.len_utf8()], "one of '#', 'O', '.' or '@' in the warehouse map"));
+    }
+    if let Some((i, c)) = moves.char_indices().find(|&(_, c)| !"^v<>\n".contains(c)) {
+        return Err(Error::unexpected(&moves[i..i + c.len_utf8()], "one of '^', 'v', '<' or '>' in the moves"));
+    }
+
     let mut robot = None;
 
-    (
+    let (grid, moves) = (
         grid.lines()
             .enumerate()
             .map(|(y, line)| {
@@ -216,8 +248,10 @@ fn parse(input: &str, part: u8) -> (Vec<Vec<u8>>, Vec<u8>, Point) {
             })
             .collect(),
         moves.lines().flat_map(|line| line.as_bytes().iter().copied()).collect(),
-        robot.unwrap(),
-    )
+    );
+    let robot = robot.ok_or_else(|| Error::parse("a robot '@' in the warehouse map"))?;
+
+    Ok((grid, moves, robot))
 }
 
 fn coordinates(grid: &[Vec<u8>]) -> usize {
@@ -237,3 +271,64 @@ fn coordinates(grid: &[Vec<u8>]) -> usize {
         })
         .sum()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+
+    const LARGE: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+    #[test]
+    fn small_example() {
+        let day = Day15::parse(SMALL).unwrap();
+        assert_eq!(day.part1().unwrap(), Answer::Int(2028));
+    }
+
+    #[test]
+    fn large_example() {
+        let day = Day15::parse(LARGE).unwrap();
+        assert_eq!(day.part1().unwrap(), Answer::Int(10092));
+        assert_eq!(day.part2().unwrap(), Answer::Int(9021));
+    }
+
+    #[test]
+    fn rejects_unknown_tiles() {
+        assert!(Day15::parse("####\n#@x#\n####\n\n<>\n").is_err());
+        assert!(Day15::parse("####\n#..#\n####\n\n<>\n").is_err());
+    }
+}
@@ -0,0 +1,198 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::solution::{Meta, Solution};
+
+const INF: usize = usize::MAX;
+const STEP: usize = 1;
+const TURN: usize = 1000;
+
+/// `(dy, dx)` of each heading in clockwise order, starting east, so a turn is one step either way.
+const HEADINGS: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+pub struct Day16 {
+    grid: Vec<Vec<char>>,
+    start: (usize, usize),
+    goal: (usize, usize),
+}
+
+impl Solution for Day16 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 16,
+        variant: None,
+        title: "Reindeer Maze",
+    };
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut grid = Vec::new();
+        let mut start = None;
+        let mut goal = None;
+
+        for (y, line) in input.lines().enumerate() {
+            let mut row = Vec::new();
+            for (x, c) in line.chars().enumerate() {
+                row.push(c);
+                if c == 'S' {
+                    start = Some((y, x));
+                } else if c == 'E' {
+                    goal = Some((y, x));
+                }
+            }
+            grid.push(row);
+        }
+
+        let start = start.ok_or_else(|| Error::parse("a start 'S' in the maze"))?;
+        let goal = goal.ok_or_else(|| Error::parse("an end 'E' in the maze"))?;
+        Ok(Day16 { grid, start, goal })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(solve(self, 1)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(solve(self, 2)?.into())
+    }
+}
+
+/// The lowest score of each `(tile, heading)` state, with the reindeer starting east on `S`.
+struct Scores {
+    width: usize,
+    scores: Vec<usize>,
+}
+
+impl Scores {
+    fn index(&self, (y, x): (usize, usize), heading: usize) -> usize {
+        (y * self.width + x) * 4 + heading
+    }
+
+    fn get(&self, tile: (usize, usize), heading: usize) -> usize {
+        self.scores[self.index(tile, heading)]
+    }
+}
+
+impl Day16 {
+    fn is_open(&self, (y, x): (usize, usize)) -> bool {
+        self.grid.get(y).and_then(|row| row.get(x)).is_some_and(|&c| c != '#')
+    }
+
+    /// The tile one step from `(y, x)` along `heading`, or backwards against it.
+    fn neighbour(&self, (y, x): (usize, usize), heading: usize, backwards: bool) -> Option<(usize, usize)> {
+        let (dy, dx) = HEADINGS[heading];
+        let (dy, dx) = if backwards { (-dy, -dx) } else { (dy, dx) };
+        let tile = (y.checked_add_signed(dy)?, x.checked_add_signed(dx)?);
+        self.is_open(tile).then_some(tile)
+    }
+
+    fn scores(&self) -> Scores {
+        let width = self.grid.iter().map(Vec::len).max().unwrap_or(0);
+        let mut scores = Scores { width, scores: vec![INF; self.grid.len() * width * 4] };
+        let mut queue = BinaryHeap::new();
+        queue.push(Reverse((0, self.start, 0)));
+
+        while let Some(Reverse((score, tile, heading))) = queue.pop() {
+            let index = scores.index(tile, heading);
+            if score >= scores.scores[index] {
+                continue;
+            }
+            scores.scores[index] = score;
+
+            if let Some(next) = self.neighbour(tile, heading, false) {
+                queue.push(Reverse((score + STEP, next, heading)));
+            }
+            for turned in [(heading + 1) % 4, (heading + 3) % 4] {
+                queue.push(Reverse((score + TURN, tile, turned)));
+            }
+        }
+        scores
+    }
+
+    fn best_score(&self, scores: &Scores) -> Result<usize> {
+        let best = (0..4).map(|heading| scores.get(self.goal, heading)).min().unwrap_or(INF);
+        if best == INF {
+            return Err(Error::puzzle("no path from S to E"));
+        }
+        Ok(best)
+    }
+}
+
+fn solve(maze: &Day16, part: usize) -> Result<usize> {
+    let scores = maze.scores();
+    let best = maze.best_score(&scores)?;
+    if part == 1 {
+        return Ok(best);
+    }
+
+    // Walk back from every best way of reaching E through the states that lead to it at no extra cost
+    let mut on_best_path = vec![false; scores.scores.len()];
+    let mut stack: Vec<_> = (0..4)
+        .filter(|&heading| scores.get(maze.goal, heading) == best)
+        .map(|heading| (maze.goal, heading))
+        .collect();
+    while let Some((tile, heading)) = stack.pop() {
+        let index = scores.index(tile, heading);
+        if on_best_path[index] {
+            continue;
+        }
+        on_best_path[index] = true;
+        let score = scores.scores[index];
+
+        if let Some(previous) = maze.neighbour(tile, heading, true) {
+            if scores.get(previous, heading).checked_add(STEP) == Some(score) {
+                stack.push((previous, heading));
+            }
+        }
+        for turned in [(heading + 1) % 4, (heading + 3) % 4] {
+            if scores.get(tile, turned).checked_add(TURN) == Some(score) {
+                stack.push((tile, turned));
+            }
+        }
+    }
+
+    Ok(on_best_path.chunks(4).filter(|headings| headings.contains(&true)).count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+
+    #[test]
+    fn example() {
+        let day = Day16::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1().unwrap(), Answer::Int(7036));
+        assert_eq!(day.part2().unwrap(), Answer::Int(45));
+    }
+
+    #[test]
+    fn requires_start_and_end() {
+        assert!(Day16::parse("####\n#S.#\n####\n").is_err());
+    }
+
+    #[test]
+    fn a_walled_off_end_has_no_path() {
+        let day = Day16::parse("#####\n#S#E#\n#####\n").unwrap();
+        assert!(day.part1().is_err());
+        assert!(day.part2().is_err());
+    }
+}
@@ -0,0 +1,17 @@
+use crate::error::Result;
+use crate::solution::{Meta, Solution};
+
+pub struct Day17 {}
+
+impl Solution for Day17 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 17,
+        variant: None,
+        title: "Chronospatial Computer",
+    };
+
+    fn parse(_input: &str) -> Result<Self> {
+        Ok(Day17 {})
+    }
+}
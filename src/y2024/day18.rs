@@ -0,0 +1,136 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::answer::Answer;
+use crate::error::{parse_num, Error, Result};
+use crate::params::Param;
+use crate::solution::{Meta, Solution};
+
+const SIZE: Param = Param::new("size", 71, "width and height of the memory space").between(1, 1_000);
+const FALLEN: Param = Param::new("bytes", 1024, "bytes that have fallen before walking to the exit");
+
+pub struct Day18 {
+    bytes: Vec<(usize, usize)>,
+}
+
+impl Solution for Day18 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 18,
+        variant: None,
+        title: "RAM Run",
+    };
+    const PARAMS: &'static [Param] = &[SIZE, FALLEN];
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Day18 { bytes: read_input(input)? })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let size = SIZE.get()?;
+
+        // Simulate memory corruption
+        let mut grid = vec![vec![true; size]; size]; // `true` = safe, `false` = corrupted
+        for &(x, y) in self.bytes.iter().take(FALLEN.get()?) {
+            if x >= size || y >= size {
+                return Err(Error::puzzle(format!("byte {},{} falls outside the memory space", x, y)));
+            }
+            grid[y][x] = false;
+        }
+
+        // Find shortest path
+        match bfs_shortest_path(&grid, (0, 0), (size - 1, size - 1)) {
+            Some(steps) => Ok(steps.into()),
+            None => Err(Error::puzzle("No path to the exit.")),
+        }
+    }
+}
+
+fn read_input(input: &str) -> Result<Vec<(usize, usize)>> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (x, y) = line
+                .split_once(',')
+                .ok_or_else(|| Error::unexpected(line, "coordinates like 'X,Y'"))?;
+            Ok((parse_num(x)?, parse_num(y)?))
+        })
+        .collect()
+}
+
+fn bfs_shortest_path(grid: &[Vec<bool>], start: (usize, usize), end: (usize, usize)) -> Option<usize> {
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+    let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)]; // Down, Right, Up, Left
+
+    queue.push_back((start, 0)); // (position, steps)
+    visited.insert(start);
+
+    while let Some(((x, y), steps)) = queue.pop_front() {
+        if (x, y) == end {
+            return Some(steps); // Reached the target
+        }
+
+        for &(dx, dy) in &directions {
+            let nx = x as isize + dx;
+            let ny = y as isize + dy;
+
+            if nx >= 0 && ny >= 0 && nx < grid.len() as isize && ny < grid.len() as isize {
+                let (nx, ny) = (nx as usize, ny as usize);
+                if grid[ny][nx] && visited.insert((nx, ny)) {
+                    queue.push_back(((nx, ny), steps + 1));
+                }
+            }
+        }
+    }
+
+    None // No path found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::params;
+
+    const EXAMPLE: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+    #[test]
+    fn example() {
+        // The example memory space is 7x7 and only the first 12 bytes have fallen
+        let overrides = [("size".to_string(), 7), ("bytes".to_string(), 12)];
+        let answer = params::with_overrides(&overrides, || Day18::parse(EXAMPLE)?.part1());
+        assert_eq!(answer.unwrap(), Answer::Int(22));
+    }
+
+    #[test]
+    fn out_of_range_bytes_are_an_error() {
+        let day = Day18::parse("71,0\n").unwrap();
+        assert!(day.part1().is_err());
+    }
+}
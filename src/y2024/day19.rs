@@ -1,31 +1,50 @@
 use std::collections::{HashMap, HashSet};
 
-pub fn run() {
-    println!("Day 19 - Linen Layout!");
-    let file_path = "src/days/inputs/day19.txt";
-    let (towel_patterns, designs) = read_input(file_path);
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::solution::{Meta, Solution};
 
-    let possible_count = count_possible_designs(&towel_patterns, &designs);
-    println!("Number of possible designs: {}", possible_count);
+pub struct Day19 {
+    towel_patterns: HashSet<String>,
+    designs: Vec<String>,
+}
+
+impl Solution for Day19 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 19,
+        variant: None,
+        title: "Linen Layout",
+    };
+
+    fn parse(input: &str) -> Result<Self> {
+        let (towel_patterns, designs) = read_input(input)?;
+        Ok(Day19 { towel_patterns, designs })
+    }
 
-    let total_possible_ways = count_all_possible_ways(&towel_patterns, &designs);
-    println!("Total possible ways to create all designs: {}", total_possible_ways);
+    fn part1(&self) -> Result<Answer> {
+        Ok(count_possible_designs(&self.towel_patterns, &self.designs).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(count_all_possible_ways(&self.towel_patterns, &self.designs).into())
+    }
 }
 
-fn read_input(file_path: &str) -> (HashSet<String>, Vec<String>) {
-    let input = std::fs::read_to_string(file_path).expect("Failed to read input file");
+fn read_input(input: &str) -> Result<(HashSet<String>, Vec<String>)> {
     let mut lines = input.lines();
     let patterns = lines
         .next()
-        .expect("Missing towel patterns")
+        .filter(|line| !line.trim().is_empty())
+        .ok_or_else(|| Error::parse("the towel patterns on the first line"))?
         .split(',')
-        .map(|s| s.trim().to_string())
+        .map(|s| s.trim().into())
         .collect();
     let designs = lines
         .skip(1) // Skip the blank line
-        .map(|s| s.trim().to_string())
+        .map(|s| s.trim().into())
         .collect();
-    (patterns, designs)
+    Ok((patterns, designs))
 }
 
 // Part 1: Count the number of designs that can be created
@@ -48,11 +67,11 @@ fn design_possible(
     }
 
     for pattern in towel_patterns {
-        if design[index..].starts_with(pattern) {
-            if design_possible(towel_patterns, design, index + pattern.len(), cache) {
-                cache.insert(index, true);
-                return true;
-            }
+        if design[index..].starts_with(pattern)
+            && design_possible(towel_patterns, design, index + pattern.len(), cache)
+        {
+            cache.insert(index, true);
+            return true;
         }
     }
 
@@ -88,3 +107,28 @@ fn design_possible_count(
     cache.insert(design.to_string(), result);
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+";
+
+    #[test]
+    fn example() {
+        let day = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1().unwrap(), Answer::Int(6));
+        assert_eq!(day.part2().unwrap(), Answer::Int(16));
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::HashSet;
+
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::params::Param;
+use crate::solution::{Meta, Solution};
+
+const CHEAT_PART1: Param = Param::new("cheat1", 2, "longest cheat in picoseconds in part 1");
+const CHEAT_PART2: Param = Param::new("cheat2", 20, "longest cheat in picoseconds in part 2");
+const MIN_SAVING: Param = Param::new("saving", 100, "picoseconds a cheat must save to be counted");
+
+pub struct Day20 {
+    track: Vec<(usize, usize)>,
+}
+
+impl Solution for Day20 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 20,
+        variant: None,
+        title: "Race Condition",
+    };
+    const PARAMS: &'static [Param] = &[CHEAT_PART1, CHEAT_PART2, MIN_SAVING];
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Day20 { track: parse_track(input)? })
+    }
+
+    // Cheats of up to `cheat1` picoseconds saving at least `saving`
+    fn part1(&self) -> Result<Answer> {
+        Ok(count_saved_steps(&self.track, CHEAT_PART1.get()?, MIN_SAVING.get()?).into())
+    }
+
+    // Cheats of up to `cheat2` picoseconds saving at least `saving`
+    fn part2(&self) -> Result<Answer> {
+        Ok(count_saved_steps(&self.track, CHEAT_PART2.get()?, MIN_SAVING.get()?).into())
+    }
+}
+
+fn parse_track(grid: &str) -> Result<Vec<(usize, usize)>> {
+    let grid: Vec<&str> = grid.lines().collect();
+    let (mut x, mut y) = grid.iter()
+        .enumerate()
+        .find_map(|(y, row)| row.chars().enumerate().find(|(_, c)| *c == 'S').map(|(x, _)| (x, y)))
+        .ok_or_else(|| Error::parse("a start position 'S' in the grid"))?;
+    
+    let mut track = vec![(x, y)];
+    let mut visited: HashSet<(usize, usize)> = HashSet::new();
+    visited.insert((x, y));
+
+    while grid[y].chars().nth(x) != Some('E') {
+        if let Some((nx, ny)) = neighbors(x, y)
+            .into_iter()
+            .find(|&(nx, ny)| {
+                // Off-grid cells are walls too, so a track without a border cannot run away
+                matches!(grid.get(ny).and_then(|row| row.chars().nth(nx)), Some('.' | 'E'))
+                    && !visited.contains(&(nx, ny))
+            })
+        {
+            track.push((nx, ny));
+            visited.insert((nx, ny));
+            x = nx;
+            y = ny;
+        } else {
+            return Err(Error::puzzle("no path from S to E"));
+        }
+    }
+
+    Ok(track)
+}
+
+fn neighbors(x: usize, y: usize) -> Vec<(usize, usize)> {
+    let mut nbs = vec![];
+    if x > 0 { nbs.push((x - 1, y)); }
+    nbs.push((x + 1, y));
+    if y > 0 { nbs.push((x, y - 1)); }
+    nbs.push((x, y + 1));
+    nbs
+}
+
+fn count_saved_steps(track: &[(usize, usize)], max_dist: usize, threshold: usize) -> usize {
+    let mut count = 0;
+
+    for (t1, &(x1, y1)) in track.iter().enumerate() {
+        for (t2, &(x2, y2)) in track.iter().enumerate().skip(t1 + 3) {
+            let dist = (x2 as isize - x1 as isize).abs() + (y2 as isize - y1 as isize).abs();
+            let path_len = t2 - t1;
+
+            if dist as usize <= max_dist && path_len > dist as usize {
+                let saved = path_len - dist as usize;
+                if saved >= threshold {
+                    count += 1;
+                }
+            }
+        }
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::params;
+
+    const EXAMPLE: &str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+
+    #[test]
+    fn example_track() {
+        let track = parse_track(EXAMPLE).unwrap();
+        assert_eq!(track.len(), 85);
+    }
+
+    #[test]
+    fn a_track_without_walls_has_no_path() {
+        let err = parse_track("S..\n").unwrap_err();
+        assert_eq!(err.to_string(), "no path from S to E");
+        assert!(parse_track("S.E\n").is_ok());
+    }
+
+    /// Runs `part` on the example, counting cheats that save at least `saving` picoseconds.
+    fn cheats(part: fn(&Day20) -> Result<Answer>, saving: u64) -> Answer {
+        let day = Day20::parse(EXAMPLE).unwrap();
+        params::with_overrides(&[("saving".to_string(), saving)], || part(&day)).unwrap()
+    }
+
+    #[test]
+    fn example_short_cheats() {
+        assert_eq!(cheats(Day20::part1, 64), Answer::Int(1));
+        assert_eq!(cheats(Day20::part1, 20), Answer::Int(5));
+        assert_eq!(cheats(Day20::part1, 1), Answer::Int(44));
+    }
+
+    #[test]
+    fn example_long_cheats() {
+        assert_eq!(cheats(Day20::part2, 76), Answer::Int(3));
+        assert_eq!(cheats(Day20::part2, 74), Answer::Int(7));
+        assert_eq!(cheats(Day20::part2, 50), Answer::Int(285));
+    }
+}
@@ -1,8 +1,8 @@
-use std::{
-    collections::{HashMap, VecDeque},
-    error::Error,
-    fs,
-};
+use std::collections::{HashMap, VecDeque};
+
+use crate::answer::Answer;
+use crate::error::{parse_num, Error, Result};
+use crate::solution::{Meta, Solution};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 enum Keys {
@@ -26,41 +26,58 @@ enum Keys {
 
 use Keys::*;
 
-fn get_input(file_path: &str) -> Result<Vec<(Vec<Keys>, usize)>, Box<dyn Error>> {
-    let input = fs::read_to_string(file_path)?;
-    let result = input
+/// A door code's key presses together with its numeric value.
+type Code = (Vec<Keys>, usize);
+
+pub struct Day21 {
+    codes: Vec<Code>,
+}
+
+impl Solution for Day21 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 21,
+        variant: None,
+        title: "Keypad Conundrum",
+    };
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Day21 { codes: get_input(input)? })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(calculate_complexities(&self.codes).into())
+    }
+}
+
+fn get_input(input: &str) -> Result<Vec<Code>> {
+    input
         .trim()
         .lines()
         .map(|line| {
             let keys = line
-                .chars()
-                .map(|c| match c {
-                    '0' => Key0,
-                    '1' => Key1,
-                    '2' => Key2,
-                    '3' => Key3,
-                    '4' => Key4,
-                    '5' => Key5,
-                    '6' => Key6,
-                    '7' => Key7,
-                    '8' => Key8,
-                    '9' => Key9,
-                    'A' => KeyA,
-                    _ => unreachable!(),
+                .char_indices()
+                .map(|(i, c)| match c {
+                    '0' => Ok(Key0),
+                    '1' => Ok(Key1),
+                    '2' => Ok(Key2),
+                    '3' => Ok(Key3),
+                    '4' => Ok(Key4),
+                    '5' => Ok(Key5),
+                    '6' => Ok(Key6),
+                    '7' => Ok(Key7),
+                    '8' => Ok(Key8),
+                    '9' => Ok(Key9),
+                    'A' => Ok(KeyA),
+                    _ => Err(Error::unexpected(&line[i..i + c.len_utf8()], "a digit or 'A' in the door code")),
                 })
-                .collect();
+                .collect::<Result<_>>()?;
 
-            let value = line
-                .chars()
-                .filter(|c| c.is_numeric())
-                .collect::<String>()
-                .parse()
-                .unwrap();
+            let value = parse_num(&line.chars().filter(|c| c.is_ascii_digit()).collect::<String>())?;
 
             Ok((keys, value))
         })
-        .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
-    Ok(result)
+        .collect()
 }
 
 fn find_shortests(
@@ -120,7 +137,7 @@ fn find_all_shortests(
         .collect()
 }
 
-fn calculate_complexities(file_path: &str) -> Result<usize, Box<dyn Error>> {
+fn calculate_complexities(codes: &[Code]) -> usize {
     let numpad: HashMap<(usize, usize), Keys> = vec![
         ((0, 0), Key7),
         ((0, 1), Key8),
@@ -149,15 +166,14 @@ fn calculate_complexities(file_path: &str) -> Result<usize, Box<dyn Error>> {
     .into_iter()
     .collect();
 
-    let codes = get_input(file_path)?;
     let mut complexities = 0;
     let shortests_paths_numpad = find_all_shortests(&numpad);
-    let shortests_paths_control = find_all_shortests(&control);
+    let _shortests_paths_control = find_all_shortests(&control);
 
     for (code, value) in codes {
         let mut previous_key = KeyA;
         let mut len = 0;
-        for key in code {
+        for &key in code {
             len += shortests_paths_numpad
                 .get(&previous_key)
                 .and_then(|paths| paths.get(&key))
@@ -168,13 +184,22 @@ fn calculate_complexities(file_path: &str) -> Result<usize, Box<dyn Error>> {
         complexities += value * len;
     }
 
-    Ok(complexities)
+    complexities
 }
 
-pub fn run() {
-    let file_path = "src/days/inputs/day21.txt";
-    match calculate_complexities(file_path) {
-        Ok(complexities) => println!("Total complexities: {}", complexities),
-        Err(e) => eprintln!("Error calculating complexities: {}", e),
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "only the numeric keypad is modelled, not the robots' directional keypads"]
+    fn example() {
+        let day = Day21::parse("029A\n980A\n179A\n456A\n379A\n").unwrap();
+        assert_eq!(day.part1().unwrap(), Answer::Int(126384));
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        assert!(Day21::parse("029A\n98xA\n").is_err());
     }
 }
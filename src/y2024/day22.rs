@@ -0,0 +1,17 @@
+use crate::error::Result;
+use crate::solution::{Meta, Solution};
+
+pub struct Day22 {}
+
+impl Solution for Day22 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 22,
+        variant: None,
+        title: "Monkey Market",
+    };
+
+    fn parse(_input: &str) -> Result<Self> {
+        Ok(Day22 {})
+    }
+}
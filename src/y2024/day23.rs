@@ -0,0 +1,17 @@
+use crate::error::Result;
+use crate::solution::{Meta, Solution};
+
+pub struct Day23 {}
+
+impl Solution for Day23 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 23,
+        variant: None,
+        title: "LAN Party",
+    };
+
+    fn parse(_input: &str) -> Result<Self> {
+        Ok(Day23 {})
+    }
+}
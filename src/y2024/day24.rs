@@ -0,0 +1,17 @@
+use crate::error::Result;
+use crate::solution::{Meta, Solution};
+
+pub struct Day24 {}
+
+impl Solution for Day24 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 24,
+        variant: None,
+        title: "Crossed Wires",
+    };
+
+    fn parse(_input: &str) -> Result<Self> {
+        Ok(Day24 {})
+    }
+}
@@ -0,0 +1,17 @@
+use crate::error::Result;
+use crate::solution::{Meta, Solution};
+
+pub struct Day25 {}
+
+impl Solution for Day25 {
+    const META: Meta = Meta {
+        year: 2024,
+        day: 25,
+        variant: None,
+        title: "Code Chronicle",
+    };
+
+    fn parse(_input: &str) -> Result<Self> {
+        Ok(Day25 {})
+    }
+}
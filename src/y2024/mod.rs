@@ -0,0 +1,58 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day14p2;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use crate::solution::Entry;
+
+/// Every registered 2024 solution, in the order they are listed and run.
+pub static REGISTRY: &[Entry] = &[
+    Entry::of::<day01::Day01>(),
+    Entry::of::<day02::Day02>(),
+    Entry::of::<day03::Day03>(),
+    Entry::of::<day04::Day04>(),
+    Entry::of::<day05::Day05>(),
+    Entry::of::<day06::Day06>(),
+    Entry::of::<day07::Day07>(),
+    Entry::of::<day08::Day08>(),
+    Entry::of::<day09::Day09>(),
+    Entry::of::<day10::Day10>(),
+    Entry::of::<day11::Day11>(),
+    Entry::of::<day12::Day12>(),
+    Entry::of::<day13::Day13>(),
+    Entry::of::<day14::Day14>(),
+    Entry::of::<day14p2::Day14p2>(),
+    Entry::of::<day15::Day15>(),
+    Entry::of::<day16::Day16>(),
+    Entry::of::<day17::Day17>(),
+    Entry::of::<day18::Day18>(),
+    Entry::of::<day19::Day19>(),
+    Entry::of::<day20::Day20>(),
+    Entry::of::<day21::Day21>(),
+    Entry::of::<day22::Day22>(),
+    Entry::of::<day23::Day23>(),
+    Entry::of::<day24::Day24>(),
+    Entry::of::<day25::Day25>(),
+];
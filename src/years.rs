@@ -0,0 +1,41 @@
+use crate::config;
+use crate::solution::Entry;
+use crate::y2024;
+
+/// The solutions of one Advent of Code event.
+pub struct Year {
+    pub year: u16,
+    pub registry: &'static [Entry],
+}
+
+/// Every event with solutions, oldest first.
+pub static YEARS: &[Year] = &[
+    Year { year: 2024, registry: y2024::REGISTRY },
+];
+
+/// The most recent year with solutions.
+pub fn latest() -> u16 {
+    YEARS[YEARS.len() - 1].year
+}
+
+/// The year used when none is given: the configured one, else the most recent.
+pub fn default_year() -> u16 {
+    config::get().year.unwrap_or_else(latest)
+}
+
+pub fn registry(year: u16) -> Option<&'static [Entry]> {
+    YEARS.iter().find(|entry| entry.year == year).map(|entry| entry.registry)
+}
+
+/// Looks up a solution by year and key, e.g. `(2024, "6")` or `(2024, "14p2")`.
+pub fn find(year: u16, key: &str) -> Option<&'static Entry> {
+    registry(year)?.iter().find(|entry| entry.meta.key() == key)
+}
+
+/// The solutions of `year`, or of every year when `None`, in the order they are listed and run.
+pub fn entries(year: Option<u16>) -> impl Iterator<Item = &'static Entry> {
+    YEARS
+        .iter()
+        .filter(move |entry| year.is_none_or(|year| entry.year == year))
+        .flat_map(|entry| entry.registry)
+}